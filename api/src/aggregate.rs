@@ -0,0 +1,427 @@
+//! Aggregation of `Measurement`s that may be in different (but compatible)
+//! units.
+//!
+//! `Aggregate` converts a set of `Measurement`s to a single unit (either one
+//! that's given, or the unit of the first `Measurement`), then calculates
+//! statistics on the converted values:
+//!
+//! ```rust
+//! use wise_units::{aggregate::Aggregate, Measurement};
+//!
+//! let samples = vec![
+//!     Measurement::try_new(500.0, "g").unwrap(),
+//!     Measurement::try_new(1.0, "kg").unwrap(),
+//!     Measurement::try_new(1.5, "kg").unwrap(),
+//! ];
+//! let aggregate = Aggregate::new(&samples).unwrap();
+//!
+//! assert_eq!(aggregate.sum(), Measurement::try_new(3000.0, "g").unwrap());
+//! assert_eq!(aggregate.mean(), Measurement::try_new(1000.0, "g").unwrap());
+//! assert_eq!(aggregate.max(), Measurement::try_new(1500.0, "g").unwrap());
+//! ```
+//!
+//! `Measurement` also implements `Sum` and `Product` (collecting into a
+//! `Result<Measurement, Error>`), for when only the total is needed:
+//!
+//! ```rust
+//! use wise_units::{Error, Measurement};
+//!
+//! let samples = vec![
+//!     Measurement::try_new(1.0, "kg").unwrap(),
+//!     Measurement::try_new(500.0, "g").unwrap(),
+//! ];
+//! let total: Result<Measurement, Error> = samples.into_iter().sum();
+//!
+//! assert_eq!(total.unwrap(), Measurement::try_new(1.5, "kg").unwrap());
+//! ```
+//!
+use crate::{Convertible, Error, Measurement, Unit};
+use std::iter::{Product, Sum};
+
+/// A set of `Measurement` values, all converted to the same `Unit`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aggregate {
+    unit: Unit,
+    values: Vec<f64>,
+}
+
+impl Aggregate {
+    /// Converts all `measurements` to the unit of the first one.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::EmptyAggregate` if `measurements` is empty.
+    /// * `Error::AggregateItem` (with the index of the offending item) if any
+    ///   of `measurements` can't be converted to the first one's unit.
+    ///
+    pub fn new<'a, I>(measurements: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = &'a Measurement>,
+    {
+        let mut measurements = measurements.into_iter().peekable();
+
+        let unit = match measurements.peek() {
+            Some(first) => first.unit().clone(),
+            None => return Err(Error::EmptyAggregate),
+        };
+
+        Self::with_unit(measurements, &unit)
+    }
+
+    /// Converts all `measurements` to `unit`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::EmptyAggregate` if `measurements` is empty.
+    /// * `Error::AggregateItem` (with the index of the offending item) if any
+    ///   of `measurements` can't be converted to `unit`.
+    ///
+    pub fn with_unit<'a, I>(measurements: I, unit: &Unit) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = &'a Measurement>,
+    {
+        let values = measurements
+            .into_iter()
+            .enumerate()
+            .map(|(index, measurement)| {
+                measurement
+                    .convert_to(unit)
+                    .map(|converted| converted.value())
+                    .map_err(|error| item_error(index, error))
+            })
+            .collect::<Result<Vec<f64>, Error>>()?;
+
+        if values.is_empty() {
+            return Err(Error::EmptyAggregate);
+        }
+
+        Ok(Self {
+            unit: unit.clone(),
+            values,
+        })
+    }
+
+    /// The `Unit` that all values were converted to.
+    ///
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        &self.unit
+    }
+
+    /// The converted values, in the order the `Measurement`s were given.
+    ///
+    #[must_use]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// The number of `Measurement`s that were aggregated.
+    ///
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Always `false`, since an `Aggregate` can't be built from zero
+    /// `Measurement`s.
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[must_use]
+    pub fn sum(&self) -> Measurement {
+        self.measurement(self.values.iter().sum())
+    }
+
+    /// The arithmetic mean.
+    ///
+    #[must_use]
+    pub fn mean(&self) -> Measurement {
+        self.measurement(self.mean_value())
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Measurement {
+        self.measurement(self.values.iter().copied().fold(f64::INFINITY, f64::min))
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Measurement {
+        self.measurement(
+            self.values
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+
+    /// The middle value; for an even number of values, the mean of the two
+    /// middle values.
+    ///
+    #[must_use]
+    pub fn median(&self) -> Measurement {
+        let mut sorted = self.values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let middle = sorted.len() / 2;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            f64::midpoint(sorted[middle - 1], sorted[middle])
+        } else {
+            sorted[middle]
+        };
+
+        self.measurement(median)
+    }
+
+    /// The population variance. Since it's the mean of squared differences,
+    /// its unit is the square of the aggregate's unit (ex. `g2`).
+    ///
+    #[must_use]
+    pub fn variance(&self) -> Measurement {
+        let mean = self.mean_value();
+
+        let variance = self
+            .values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / self.count();
+
+        Measurement::new(variance, &self.unit * &self.unit)
+    }
+
+    fn mean_value(&self) -> f64 {
+        self.values.iter().sum::<f64>() / self.count()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    const fn count(&self) -> f64 {
+        self.values.len() as f64
+    }
+
+    fn measurement(&self, value: f64) -> Measurement {
+        Measurement::new(value, self.unit.clone())
+    }
+}
+
+fn item_error(index: usize, error: Error) -> Error {
+    Error::AggregateItem {
+        index,
+        source: Box::new(error),
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Sum
+//-----------------------------------------------------------------------------
+/// Sums all `Measurement`s, in the unit of the first one.
+///
+/// Results in an `Error::EmptyAggregate` if there are no `Measurement`s, or an
+/// `Error::AggregateItem` if any `Measurement` can't be converted to the first
+/// one's unit.
+///
+impl Sum<Measurement> for Result<Measurement, Error> {
+    fn sum<I: Iterator<Item = Measurement>>(iter: I) -> Self {
+        sum_measurements(iter)
+    }
+}
+
+impl<'a> Sum<&'a Measurement> for Result<Measurement, Error> {
+    fn sum<I: Iterator<Item = &'a Measurement>>(iter: I) -> Self {
+        sum_measurements(iter)
+    }
+}
+
+fn sum_measurements<M, I>(iter: I) -> Result<Measurement, Error>
+where
+    M: AsRef<Measurement>,
+    I: Iterator<Item = M>,
+{
+    let mut iter = iter.enumerate();
+
+    let mut total = match iter.next() {
+        Some((_, first)) => first.as_ref().clone(),
+        None => return Err(Error::EmptyAggregate),
+    };
+
+    for (index, measurement) in iter {
+        total = (&total + measurement.as_ref()).map_err(|error| item_error(index, error))?;
+    }
+
+    Ok(total)
+}
+
+//-----------------------------------------------------------------------------
+// impl Product
+//-----------------------------------------------------------------------------
+/// Multiplies all `Measurement`s together. An empty iterator results in `1`
+/// (with the unit `1`).
+///
+/// Results in an `Error::AggregateItem` if multiplying by any `Measurement`
+/// fails (ex. its unit's exponents overflow).
+///
+impl Product<Measurement> for Result<Measurement, Error> {
+    fn product<I: Iterator<Item = Measurement>>(iter: I) -> Self {
+        multiply_measurements(iter)
+    }
+}
+
+impl<'a> Product<&'a Measurement> for Result<Measurement, Error> {
+    fn product<I: Iterator<Item = &'a Measurement>>(iter: I) -> Self {
+        multiply_measurements(iter)
+    }
+}
+
+fn multiply_measurements<M, I>(iter: I) -> Result<Measurement, Error>
+where
+    M: AsRef<Measurement>,
+    I: Iterator<Item = M>,
+{
+    let mut iter = iter.enumerate();
+
+    let mut total = match iter.next() {
+        Some((_, first)) => first.as_ref().clone(),
+        None => return Measurement::try_new(1.0, "1"),
+    };
+
+    for (index, measurement) in iter {
+        total = total
+            .checked_mul(measurement.as_ref())
+            .map_err(|error| item_error(index, error))?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    fn samples() -> Vec<Measurement> {
+        vec![
+            measurement(1.0, "kg"),
+            measurement(500.0, "g"),
+            measurement(1.0, "[lb_av]"),
+            measurement(2.0, "kg"),
+        ]
+    }
+
+    #[test]
+    fn validate_new() {
+        let aggregate = Aggregate::new(&samples()).unwrap();
+
+        assert_eq!(aggregate.unit(), &Unit::from_str("kg").unwrap());
+        assert_eq!(aggregate.len(), 4);
+        assert_relative_eq!(aggregate.values()[1], 0.5);
+        assert_relative_eq!(aggregate.values()[2], 0.453_592_37);
+
+        assert_eq!(
+            Aggregate::new(&Vec::<Measurement>::new()).unwrap_err(),
+            Error::EmptyAggregate
+        );
+    }
+
+    #[test]
+    fn validate_with_unit() {
+        let unit = Unit::from_str("g").unwrap();
+        let aggregate = Aggregate::with_unit(&samples(), &unit).unwrap();
+
+        assert_eq!(aggregate.unit(), &unit);
+        assert_relative_eq!(aggregate.sum().value(), 3_953.592_37);
+    }
+
+    #[test]
+    fn validate_incompatible_item() {
+        let mut samples = samples();
+        samples.insert(2, measurement(1.0, "m"));
+
+        match Aggregate::new(&samples).unwrap_err() {
+            Error::AggregateItem { index, source } => {
+                assert_eq!(index, 2);
+                assert!(matches!(*source, Error::IncompatibleUnitTypes { .. }));
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_statistics() {
+        let aggregate = Aggregate::new(&samples()).unwrap();
+
+        assert_relative_eq!(aggregate.sum().value(), 3.953_592_37);
+        assert_relative_eq!(aggregate.mean().value(), 0.988_398_092_5);
+        assert_relative_eq!(aggregate.min().value(), 0.453_592_37);
+        assert_relative_eq!(aggregate.max().value(), 2.0);
+        assert_relative_eq!(aggregate.median().value(), 0.75);
+
+        let aggregate = Aggregate::new(&samples()[..3]).unwrap();
+        assert_relative_eq!(aggregate.median().value(), 0.5);
+    }
+
+    #[test]
+    fn validate_variance() {
+        let samples = vec![
+            measurement(2.0, "m"),
+            measurement(400.0, "cm"),
+            measurement(4.0, "m"),
+            measurement(4.0, "m"),
+            measurement(5.0, "m"),
+            measurement(5.0, "m"),
+            measurement(7.0, "m"),
+            measurement(9.0, "m"),
+        ];
+        let variance = Aggregate::new(&samples).unwrap().variance();
+
+        assert_relative_eq!(variance.value(), 4.0);
+        assert_eq!(variance.unit().expression(), "m2");
+    }
+
+    #[test]
+    fn validate_sum() {
+        let total: Result<Measurement, Error> = samples().iter().sum();
+        assert_relative_eq!(total.unwrap().value(), 3.953_592_37);
+
+        let total: Result<Measurement, Error> = samples().into_iter().sum();
+        assert_eq!(total.unwrap().unit().expression(), "kg");
+
+        let total: Result<Measurement, Error> = Vec::<Measurement>::new().into_iter().sum();
+        assert_eq!(total.unwrap_err(), Error::EmptyAggregate);
+
+        let total: Result<Measurement, Error> =
+            [measurement(1.0, "g"), measurement(1.0, "s")].iter().sum();
+        assert!(matches!(
+            total.unwrap_err(),
+            Error::AggregateItem { index: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn validate_product() {
+        let product: Result<Measurement, Error> = [measurement(2.0, "m"), measurement(3.0, "s")]
+            .iter()
+            .product();
+        assert_eq!(product.unwrap(), measurement(6.0, "m.s"));
+
+        let product: Result<Measurement, Error> = Vec::<Measurement>::new().into_iter().product();
+        assert_eq!(product.unwrap(), measurement(1.0, "1"));
+
+        let product: Result<Measurement, Error> =
+            vec![measurement(1.0, "s-2147483647"), measurement(1.0, "s-2")]
+                .into_iter()
+                .product();
+        assert!(matches!(
+            product.unwrap_err(),
+            Error::AggregateItem { index: 1, .. }
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+//! How annotations (ex. the `{creat}` in `mg{creat}`) affect whether units are
+//! compatible.
+//!
+//! By default, annotations must match exactly, so `mg{creat}` can't be
+//! converted to, compared with, or added to `mg`. Since UCUM says annotations
+//! don't change a unit's meaning, that's stricter than some integrations want.
+//! `with_annotation_policy()` runs a closure with a different
+//! `AnnotationPolicy`; the policy applies to everything that checks
+//! compatibility (`IsCompatibleWith`, `Convertible`, `PartialEq`, `PartialOrd`
+//! and arithmetic on `Measurement`s) on the current thread, until the closure
+//! returns.
+//!
+//! ```rust
+//! use wise_units::{
+//!     annotation_policy::{with_annotation_policy, AnnotationPolicy},
+//!     Convertible, Measurement,
+//! };
+//!
+//! let creatinine = Measurement::try_new(500.0, "mg{creat}").unwrap();
+//! assert!(creatinine.convert_to("g").is_err());
+//!
+//! let grams = with_annotation_policy(AnnotationPolicy::Ignore, || {
+//!     creatinine.convert_to("g").unwrap()
+//! });
+//! assert_eq!(grams.value(), 0.5);
+//! ```
+//!
+use crate::scoped;
+use std::{borrow::Cow, cell::RefCell, collections::HashMap};
+
+thread_local! {
+    static POLICY: RefCell<AnnotationPolicy> = RefCell::new(AnnotationPolicy::default());
+}
+
+/// How annotations are compared when checking compatibility.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationPolicy {
+    /// Annotations must match exactly (ex. `mg{creat}` is only compatible
+    /// with other `{creat}` units).
+    #[default]
+    Strict,
+
+    /// Annotations are ignored (ex. `mg{creat}` is compatible with `mg` and
+    /// `mg{tot}`).
+    Ignore,
+
+    /// Annotations must match, ignoring case (ex. `{RBC}` and `{rbc}`).
+    CaseInsensitive,
+
+    /// Annotations must match, or be in the same group (ex. `{creat}` and
+    /// `{creatinine}`).
+    Equivalent(EquivalenceGroups),
+}
+
+/// Groups of annotations that are considered the same.
+///
+/// ```rust
+/// use wise_units::annotation_policy::EquivalenceGroups;
+///
+/// let groups = EquivalenceGroups::new()
+///     .with_group(["creat", "creatinine", "Cr"])
+///     .with_group(["tot", "total"]);
+///
+/// assert!(groups.are_equivalent("creatinine", "Cr"));
+/// assert!(!groups.are_equivalent("creat", "tot"));
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EquivalenceGroups {
+    /// Each grouped annotation, and the annotation that represents its group.
+    canonical: HashMap<String, String>,
+}
+
+impl EquivalenceGroups {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a group of equivalent annotations. If any of them are already in
+    /// a group, the groups are merged.
+    ///
+    #[must_use]
+    pub fn with_group<I, S>(mut self, group: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let group: Vec<String> = group.into_iter().map(Into::into).collect();
+
+        let Some(first) = group.first() else {
+            return self;
+        };
+
+        let canonical = self
+            .canonical
+            .get(first)
+            .cloned()
+            .unwrap_or_else(|| first.clone());
+
+        for annotation in group {
+            if let Some(previous) = self.canonical.get(&annotation).cloned() {
+                for value in self.canonical.values_mut() {
+                    if *value == previous {
+                        value.clone_from(&canonical);
+                    }
+                }
+            }
+
+            let _ = self.canonical.insert(annotation, canonical.clone());
+        }
+
+        self
+    }
+
+    /// Whether `lhs` and `rhs` are the same annotation, or are in the same
+    /// group.
+    ///
+    #[must_use]
+    pub fn are_equivalent(&self, lhs: &str, rhs: &str) -> bool {
+        self.canonical_of(lhs) == self.canonical_of(rhs)
+    }
+
+    fn canonical_of<'a>(&'a self, annotation: &'a str) -> &'a str {
+        self.canonical
+            .get(annotation)
+            .map_or(annotation, String::as_str)
+    }
+}
+
+impl AnnotationPolicy {
+    /// The key that `annotation` is compared by under `self`; `None` means
+    /// the annotation doesn't count. Annotations that are already their own
+    /// key are borrowed, rather than copied.
+    ///
+    fn key<'a>(&self, annotation: &'a str) -> Option<Cow<'a, str>> {
+        match self {
+            Self::Strict => Some(Cow::Borrowed(annotation)),
+            Self::Ignore => None,
+            Self::CaseInsensitive => {
+                if annotation
+                    .chars()
+                    .flat_map(char::to_lowercase)
+                    .eq(annotation.chars())
+                {
+                    Some(Cow::Borrowed(annotation))
+                } else {
+                    Some(Cow::Owned(annotation.to_lowercase()))
+                }
+            }
+            Self::Equivalent(groups) => {
+                let canonical = groups.canonical_of(annotation);
+
+                if canonical == annotation {
+                    Some(Cow::Borrowed(annotation))
+                } else {
+                    Some(Cow::Owned(canonical.to_string()))
+                }
+            }
+        }
+    }
+}
+
+/// The `AnnotationPolicy` in effect on the current thread.
+///
+#[must_use]
+pub fn annotation_policy() -> AnnotationPolicy {
+    POLICY.with(|policy| policy.borrow().clone())
+}
+
+/// Runs `f` with `policy` in effect on the current thread. The previous
+/// policy is back in effect once `f` returns or panics.
+///
+pub fn with_annotation_policy<F, R>(policy: AnnotationPolicy, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    scoped::with_value(&POLICY, policy, f)
+}
+
+/// The key that `annotation` is compared by under the current policy; `None`
+/// means the annotation doesn't count towards compatibility.
+///
+pub(crate) fn annotation_key(annotation: &str) -> Option<Cow<'_, str>> {
+    POLICY.with(|policy| policy.borrow().key(annotation))
+}
+
+/// Whether `lhs` and `rhs` are the same annotation under the current policy.
+/// Under `AnnotationPolicy::Strict`, they're compared as they are.
+///
+pub(crate) fn annotations_match(lhs: Option<&str>, rhs: Option<&str>) -> bool {
+    POLICY.with(|policy| match &*policy.borrow() {
+        AnnotationPolicy::Strict => lhs == rhs,
+        policy => lhs.and_then(|lhs| policy.key(lhs)) == rhs.and_then(|rhs| policy.key(rhs)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Convertible, IsCompatibleWith, Measurement, Unit};
+    use std::str::FromStr;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_strict() {
+        assert_eq!(annotation_policy(), AnnotationPolicy::Strict);
+        assert!(!unit("mg{creat}").is_compatible_with(&unit("mg")));
+        assert!(unit("mg{creat}").is_compatible_with(&unit("g{creat}")));
+        assert!(!unit("{RBC}").is_compatible_with(&unit("{rbc}")));
+    }
+
+    #[test]
+    fn validate_ignore() {
+        with_annotation_policy(AnnotationPolicy::Ignore, || {
+            assert!(unit("mg{creat}").is_compatible_with(&unit("mg")));
+            assert!(unit("mg{creat}").is_compatible_with(&unit("g{tot}")));
+            assert!(unit("{tablet}").is_compatible_with(&unit("1")));
+            assert!(!unit("mg{creat}").is_compatible_with(&unit("m")));
+
+            assert_eq!(unit("g{creat}"), unit("1000mg"));
+            assert!(unit("g{creat}") > unit("mg"));
+            assert_eq!(
+                measurement(1.0, "g{creat}").convert_to("mg").unwrap(),
+                measurement(1000.0, "mg")
+            );
+            assert_eq!(
+                (measurement(1.0, "g{creat}") + measurement(500.0, "mg")).unwrap(),
+                measurement(1.5, "g")
+            );
+        });
+
+        assert!(!unit("mg{creat}").is_compatible_with(&unit("mg")));
+    }
+
+    #[test]
+    fn validate_case_insensitive() {
+        with_annotation_policy(AnnotationPolicy::CaseInsensitive, || {
+            assert!(unit("10*3{RBC}/uL").is_compatible_with(&unit("10*3{rbc}/uL")));
+            assert!(!unit("mg{creat}").is_compatible_with(&unit("mg")));
+        });
+    }
+
+    #[test]
+    fn validate_equivalence_groups() {
+        let groups = EquivalenceGroups::new()
+            .with_group(["creat", "creatinine"])
+            .with_group(["Cr", "creat"]);
+        assert!(groups.are_equivalent("Cr", "creatinine"));
+
+        with_annotation_policy(AnnotationPolicy::Equivalent(groups), || {
+            assert!(unit("mg{creatinine}").is_compatible_with(&unit("g{Cr}")));
+            assert!(unit("mg{creat}/dL").is_compatible_with(&unit("g{creatinine}/L")));
+            assert!(!unit("mg{creat}").is_compatible_with(&unit("mg{tot}")));
+            assert!(!unit("mg{creat}").is_compatible_with(&unit("mg")));
+        });
+    }
+
+    #[test]
+    fn validate_keys_are_borrowed_when_unchanged() {
+        assert!(matches!(
+            AnnotationPolicy::Strict.key("RBC"),
+            Some(Cow::Borrowed("RBC"))
+        ));
+        assert!(matches!(
+            AnnotationPolicy::CaseInsensitive.key("rbc"),
+            Some(Cow::Borrowed("rbc"))
+        ));
+        assert!(matches!(
+            AnnotationPolicy::CaseInsensitive.key("RBC"),
+            Some(Cow::Owned(key)) if key == "rbc"
+        ));
+
+        let groups = EquivalenceGroups::new().with_group(["creat", "creatinine"]);
+        let policy = AnnotationPolicy::Equivalent(groups);
+        assert!(matches!(policy.key("creat"), Some(Cow::Borrowed("creat"))));
+        assert!(matches!(
+            policy.key("creatinine"),
+            Some(Cow::Owned(key)) if key == "creat"
+        ));
+        assert_eq!(AnnotationPolicy::Ignore.key("creat"), None);
+    }
+
+    #[test]
+    fn validate_annotations_match() {
+        assert!(annotations_match(Some("creat"), Some("creat")));
+        assert!(annotations_match(None, None));
+        assert!(!annotations_match(Some("creat"), None));
+        assert!(!annotations_match(Some("RBC"), Some("rbc")));
+
+        with_annotation_policy(AnnotationPolicy::CaseInsensitive, || {
+            assert!(annotations_match(Some("RBC"), Some("rbc")));
+            assert!(!annotations_match(Some("RBC"), None));
+        });
+
+        with_annotation_policy(AnnotationPolicy::Ignore, || {
+            assert!(annotations_match(Some("creat"), None));
+        });
+    }
+
+    #[test]
+    fn validate_nesting_and_restoring() {
+        with_annotation_policy(AnnotationPolicy::Ignore, || {
+            with_annotation_policy(AnnotationPolicy::Strict, || {
+                assert_eq!(annotation_policy(), AnnotationPolicy::Strict);
+            });
+
+            assert_eq!(annotation_policy(), AnnotationPolicy::Ignore);
+        });
+
+        let result = std::panic::catch_unwind(|| {
+            with_annotation_policy(AnnotationPolicy::Ignore, || panic!("meow"));
+        });
+
+        assert!(result.is_err());
+        assert_eq!(annotation_policy(), AnnotationPolicy::Strict);
+    }
+}
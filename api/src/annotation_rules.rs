@@ -0,0 +1,279 @@
+//! Conversion rules for count-like annotations (ex. `{tbl}`, `{pack}`,
+//! `{bag}`).
+//!
+//! On their own, annotation-only units can only be converted to units with the
+//! same annotation. `AnnotationRules` declares what one of an annotation is
+//! equal to (ex. `1 {pack} = 20 {tbl}`, or `1 {bag} = 50 [lb_av]`);
+//! `with_annotation_rules()` runs a closure with those rules in effect on the
+//! current thread, so that `Convertible` can use them to convert between
+//! units that would otherwise be incompatible.
+//!
+//! ```rust
+//! use wise_units::{
+//!     annotation_rules::{with_annotation_rules, AnnotationRules},
+//!     Convertible, Measurement,
+//! };
+//!
+//! let rules = AnnotationRules::new()
+//!     .with_rule("{pack}", 20.0, "{tbl}")
+//!     .unwrap()
+//!     .with_rule("{bag}", 50.0, "[lb_av]")
+//!     .unwrap();
+//!
+//! let packs = Measurement::try_new(2.0, "{pack}").unwrap();
+//! assert!(packs.convert_to("{tbl}").is_err());
+//!
+//! with_annotation_rules(rules, || {
+//!     assert_eq!(packs.convert_to("{tbl}").unwrap().value(), 40.0);
+//!
+//!     let bags = Measurement::try_new(1.0, "{bag}").unwrap();
+//!     assert_eq!(bags.convert_to("[lb_av]").unwrap().value(), 50.0);
+//! });
+//! ```
+//!
+use crate::{parser::Term, scoped, ucum_unit::UcumUnit, Error, Unit};
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
+
+thread_local! {
+    static RULES: RefCell<AnnotationRules> = RefCell::new(AnnotationRules::default());
+}
+
+/// What one of an annotation is equal to.
+///
+#[derive(Clone, Debug, PartialEq)]
+struct Rule {
+    value: f64,
+    unit: Unit,
+}
+
+/// A set of rules that define annotation-only units in terms of other units.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnnotationRules {
+    rules: HashMap<String, Rule>,
+}
+
+impl AnnotationRules {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rule `1 annotation = value unit` (ex. `"{pack}", 20.0,
+    /// "{tbl}"` for `1 {pack} = 20 {tbl}`). Rules can refer to other
+    /// annotations that have rules; adding a rule for an annotation that
+    /// already has one replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::ParsingFailed` if either expression is invalid, or
+    /// an `Error::InvalidAnnotationRule` if `annotation` isn't a single
+    /// annotation (ex. `{pack}`), if `unit` is a special unit (ex. `Cel`), or
+    /// if `value` isn't positive.
+    ///
+    pub fn with_rule(mut self, annotation: &str, value: f64, unit: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidAnnotationRule {
+            lhs: annotation.to_string(),
+            rhs: format!("{value} {unit}"),
+        };
+
+        let lhs = Unit::from_str(annotation)?;
+        let rhs = Unit::from_str(unit)?;
+
+        let key = match lhs.terms().as_slice() {
+            [term]
+                if term.factor.is_none()
+                    && term.prefix.is_none()
+                    && term.atom.is_none()
+                    && term.exponent.is_none() =>
+            {
+                term.annotation.clone().ok_or_else(invalid)?
+            }
+            _ => return Err(invalid()),
+        };
+
+        if rhs.is_special() || !value.is_normal() || value < 0.0 {
+            return Err(invalid());
+        }
+
+        let _ = self.rules.insert(key, Rule { value, unit: rhs });
+
+        Ok(self)
+    }
+
+    /// Replaces every annotation-only term in `unit` that has a rule with the
+    /// rule's unit, returning the scale of the new unit relative to the old
+    /// one, and the new unit. Annotations in the rules' units are replaced
+    /// too, as long as that doesn't loop back to an annotation that's being
+    /// replaced.
+    ///
+    fn expand(&self, unit: &Unit) -> Option<(f64, Unit)> {
+        let mut scale = 1.0;
+        let mut terms = Vec::with_capacity(unit.terms().len());
+        let mut expanding = Vec::new();
+        let mut changed = false;
+
+        for term in unit.terms() {
+            changed |= self.expand_term(term.clone(), &mut expanding, &mut scale, &mut terms);
+        }
+
+        changed.then(|| (scale, Unit::new(terms)))
+    }
+
+    fn expand_term<'a>(
+        &'a self,
+        mut term: Term,
+        expanding: &mut Vec<&'a str>,
+        scale: &mut f64,
+        terms: &mut Vec<Term>,
+    ) -> bool {
+        let rule = term
+            .annotation
+            .as_deref()
+            .filter(|_| term.atom.is_none() && term.prefix.is_none())
+            .and_then(|annotation| self.rules.get_key_value(annotation))
+            .filter(|(annotation, _)| !expanding.contains(&annotation.as_str()));
+
+        let Some((annotation, rule)) = rule else {
+            terms.push(term);
+            return false;
+        };
+
+        let exponent = term.exponent.unwrap_or(1);
+        *scale *= rule.value.powi(exponent);
+
+        if term.factor.is_some() {
+            term.annotation = None;
+            terms.push(term);
+        }
+
+        expanding.push(annotation);
+
+        for rule_term in rule.unit.terms() {
+            let mut rule_term = rule_term.clone();
+            rule_term.exponent =
+                Some(rule_term.exponent.unwrap_or(1) * exponent).filter(|exponent| *exponent != 1);
+
+            let _ = self.expand_term(rule_term, expanding, scale, terms);
+        }
+
+        let _ = expanding.pop();
+
+        true
+    }
+}
+
+/// Runs `f` with `rules` in effect on the current thread. Nested rules
+/// replace outer ones rather than adding to them.
+///
+pub fn with_annotation_rules<F, R>(rules: AnnotationRules, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    scoped::with_value(&RULES, rules, f)
+}
+
+/// Expands `unit` using the current thread's rules; `None` means no rules
+/// applied. See `AnnotationRules::expand()`.
+///
+pub(crate) fn expand_annotations(unit: &Unit) -> Option<(f64, Unit)> {
+    RULES.with(|rules| rules.borrow().expand(unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Convertible, Measurement};
+    use approx::assert_relative_eq;
+
+    fn rules() -> AnnotationRules {
+        AnnotationRules::new()
+            .with_rule("{pack}", 20.0, "{tbl}")
+            .unwrap()
+            .with_rule("{tbl}", 250.0, "mg")
+            .unwrap()
+            .with_rule("{bag}", 50.0, "[lb_av]")
+            .unwrap()
+    }
+
+    fn convert(value: f64, from: &str, to: &str) -> Result<f64, Error> {
+        Measurement::try_new(value, from)
+            .unwrap()
+            .convert_to(to)
+            .map(|measurement| measurement.value())
+    }
+
+    #[test]
+    fn validate_with_rule_errors() {
+        let rules = AnnotationRules::new();
+
+        assert!(rules.clone().with_rule("mg{pack}", 20.0, "{tbl}").is_err());
+        assert!(rules.clone().with_rule("/{pack}", 20.0, "{tbl}").is_err());
+        assert!(rules.clone().with_rule("10{pack}", 20.0, "{tbl}").is_err());
+        assert!(rules.clone().with_rule("{pack}", 20.0, "Cel").is_err());
+        assert!(rules.clone().with_rule("{pack}", 0.0, "{tbl}").is_err());
+        assert!(rules.clone().with_rule("{pack}", -1.0, "{tbl}").is_err());
+        assert!(matches!(
+            rules.with_rule("{pack", 20.0, "{tbl}"),
+            Err(Error::ParsingFailed(_))
+        ));
+    }
+
+    #[test]
+    fn validate_convert_between_annotations() {
+        with_annotation_rules(rules(), || {
+            assert_relative_eq!(convert(2.0, "{pack}", "{tbl}").unwrap(), 40.0);
+            assert_relative_eq!(convert(40.0, "{tbl}", "{pack}").unwrap(), 2.0);
+            assert_relative_eq!(convert(1.0, "{pack}/d", "{tbl}/h").unwrap(), 20.0 / 24.0);
+            assert_relative_eq!(convert(1.0, "/{tbl}", "/{pack}").unwrap(), 20.0);
+            assert_relative_eq!(convert(1.0, "{pack}.{pack}", "{tbl}.{tbl}").unwrap(), 400.0);
+        });
+    }
+
+    #[test]
+    fn validate_convert_to_physical_units() {
+        with_annotation_rules(rules(), || {
+            assert_relative_eq!(convert(1.0, "{bag}", "kg").unwrap(), 22.679_618_5);
+            assert_relative_eq!(convert(100.0, "[lb_av]", "{bag}").unwrap(), 2.0);
+            assert_relative_eq!(convert(1.0, "{pack}", "g").unwrap(), 5.0);
+            assert_relative_eq!(convert(2.0, "10{tbl}", "g").unwrap(), 5.0);
+            assert_relative_eq!(
+                convert(1.0, "{pack}/{bag}", "g/kg").unwrap(),
+                5.0 / 22.679_618_5
+            );
+
+            assert!(convert(1.0, "{bag}", "m").is_err());
+            assert!(convert(1.0, "{box}", "{tbl}").is_err());
+        });
+    }
+
+    #[test]
+    fn validate_cycles() {
+        let rules = AnnotationRules::new()
+            .with_rule("{a}", 2.0, "{b}")
+            .unwrap()
+            .with_rule("{b}", 0.5, "{a}")
+            .unwrap();
+
+        with_annotation_rules(rules, || {
+            assert!(convert(1.0, "{a}", "m").is_err());
+            assert!(convert(1.0, "{a}.{a}", "m").is_err());
+        });
+    }
+
+    #[test]
+    fn validate_restoring() {
+        with_annotation_rules(rules(), || {
+            assert!(convert(1.0, "{pack}", "{tbl}").is_ok());
+
+            with_annotation_rules(AnnotationRules::new(), || {
+                assert!(convert(1.0, "{pack}", "{tbl}").is_err());
+            });
+        });
+
+        assert!(matches!(
+            convert(1.0, "{pack}", "{tbl}"),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+    }
+}
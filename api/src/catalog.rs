@@ -0,0 +1,139 @@
+//! Functions for querying the set of known `Atom`s: by dimension, by property,
+//! by classification, or by the `UcumUnit` flags.
+//!
+//! Each function returns an iterator, so queries can be combined:
+//!
+//! ```rust
+//! use wise_units::{catalog, Classification, UcumUnit};
+//!
+//! let metric_troy_units: Vec<_> = catalog::atoms_in_classification(Classification::Troy)
+//!     .filter(UcumUnit::is_metric)
+//!     .collect();
+//! assert!(metric_troy_units.is_empty());
+//! ```
+//!
+use crate::{
+    parser::{Atom, Classification, Composable, Composition, Property, UcumSymbol},
+    ucum_unit::UcumUnit,
+};
+
+/// All `Atom`s that have the dimension `composition`.
+///
+/// ```rust
+/// use wise_units::{catalog, composition, Atom};
+///
+/// let lengths: Vec<Atom> = catalog::atoms_with_composition(composition::LENGTH).collect();
+/// assert!(lengths.contains(&Atom::Meter));
+/// assert!(lengths.contains(&Atom::InchInternational));
+/// assert!(!lengths.contains(&Atom::Liter));
+/// ```
+///
+pub fn atoms_with_composition(composition: Composition) -> impl Iterator<Item = Atom> {
+    Atom::iter().filter(move |atom| atom.composition() == composition)
+}
+
+/// All `Atom`s that are used to measure `property`.
+///
+/// ```rust
+/// use wise_units::{catalog, Atom, Property};
+///
+/// let doses: Vec<Atom> = catalog::atoms_with_property(Property::DoseEquivalent).collect();
+/// assert_eq!(doses, vec![Atom::Sievert, Atom::RadiationEquivalentMan]);
+/// ```
+///
+pub fn atoms_with_property(property: Property) -> impl Iterator<Item = Atom> {
+    property.atoms().into_iter()
+}
+
+/// All `Atom`s that UCUM groups into `classification`.
+///
+/// ```rust
+/// use wise_units::{catalog, Atom, Classification};
+///
+/// let troy: Vec<Atom> = catalog::atoms_in_classification(Classification::Troy).collect();
+/// assert_eq!(troy, vec![Atom::PennyweightTroy, Atom::OunceTroy, Atom::PoundTroy]);
+/// ```
+///
+pub fn atoms_in_classification(classification: Classification) -> impl Iterator<Item = Atom> {
+    Atom::iter().filter(move |atom| atom.classification() == classification)
+}
+
+/// All `Atom`s that may be used with a `Prefix`.
+///
+pub fn metric_atoms() -> impl Iterator<Item = Atom> {
+    Atom::iter().filter(UcumUnit::is_metric)
+}
+
+/// All `Atom`s that are converted using special (non-linear) functions.
+///
+pub fn special_atoms() -> impl Iterator<Item = Atom> {
+    Atom::iter().filter(UcumUnit::is_special)
+}
+
+/// All arbitrary `Atom`s (which can't be converted to any other unit).
+///
+pub fn arbitrary_atoms() -> impl Iterator<Item = Atom> {
+    Atom::iter().filter(UcumUnit::is_arbitrary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{composition, Prefix};
+
+    #[test]
+    fn validate_atom_iter() {
+        let atoms: Vec<Atom> = Atom::iter().collect();
+
+        assert_eq!(atoms.first(), Some(&Atom::Meter));
+        assert_eq!(atoms.last(), Some(&Atom::Baud));
+        assert!(atoms.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn validate_prefix_iter() {
+        let prefixes: Vec<Prefix> = Prefix::iter().collect();
+
+        assert_eq!(prefixes.len(), 32);
+        assert!(prefixes.contains(&Prefix::Kibi));
+        assert!(prefixes.contains(&Prefix::Quetta));
+    }
+
+    #[test]
+    fn validate_atoms_with_composition() {
+        let times: Vec<Atom> = atoms_with_composition(composition::TIME).collect();
+
+        assert!(times.contains(&Atom::Second));
+        assert!(times.contains(&Atom::Week));
+        assert!(times
+            .iter()
+            .all(|atom| atom.composition() == composition::TIME));
+    }
+
+    #[test]
+    fn validate_atoms_with_property() {
+        let radioactivity: Vec<Atom> = atoms_with_property(Property::Radioactivity).collect();
+
+        assert_eq!(radioactivity, vec![Atom::Becquerel, Atom::Curie]);
+    }
+
+    #[test]
+    fn validate_atoms_in_classification() {
+        assert!(atoms_in_classification(Classification::BritVolumes)
+            .all(|atom| atom.classification() == Classification::BritVolumes));
+        assert!(atoms_in_classification(Classification::UsVolumes)
+            .any(|atom| atom == Atom::QueenAnnesWineGallonUS));
+    }
+
+    #[test]
+    fn validate_flag_filters() {
+        assert!(metric_atoms().any(|atom| atom == Atom::Meter));
+        assert!(!metric_atoms().any(|atom| atom == Atom::InchInternational));
+
+        assert!(special_atoms().any(|atom| atom == Atom::DegreeCelsius));
+        assert!(!special_atoms().any(|atom| atom == Atom::Kelvin));
+
+        assert!(arbitrary_atoms().any(|atom| atom == Atom::InternationalUnit));
+        assert!(!arbitrary_atoms().any(|atom| atom == Atom::Meter));
+    }
+}
@@ -0,0 +1,346 @@
+//! Conversions between time `Measurement`s and `std::time::Duration` (and,
+//! with the `chrono` feature, `chrono::Duration`).
+//!
+//! Months and years don't have a fixed length in a calendar, so converting
+//! them to a `Duration` takes a `CalendarPolicy` that says how to handle them.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use wise_units::{duration::CalendarPolicy, Measurement};
+//!
+//! let measurement = Measurement::try_new(1.5, "h").unwrap();
+//! assert_eq!(
+//!     measurement.to_duration(CalendarPolicy::Reject).unwrap(),
+//!     Duration::from_secs(5400)
+//! );
+//!
+//! let measurement = Measurement::try_new(1.0, "mo").unwrap();
+//! assert!(measurement.to_duration(CalendarPolicy::Reject).is_err());
+//! assert_eq!(
+//!     measurement.to_duration(CalendarPolicy::Nominal).unwrap(),
+//!     Duration::from_secs(30 * 86_400)
+//! );
+//! ```
+//!
+use crate::{
+    composition,
+    parser::{Atom, Composable, Term},
+    Convertible, Error, Measurement, Unit,
+};
+use std::time::Duration;
+
+fn second_unit() -> Unit {
+    Unit::new(vec![Term::new(None, Some(Atom::Second))])
+}
+
+/// How to convert atoms that don't have a fixed length in a calendar (months
+/// and years: `mo`, `mo_j`, `mo_g`, `mo_s`, `a`, `a_j`, `a_g`, `a_t`).
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CalendarPolicy {
+    /// Fail with an `Error::AmbiguousCalendarUnit`.
+    #[default]
+    Reject,
+
+    /// Use the UCUM definitions, which are mean lengths (ex. `a_g` is 365.2425
+    /// days, `mo_j` is 1/12 of 365.25 days).
+    Mean,
+
+    /// Use the nominal lengths used for scheduling: 30 days for any month and
+    /// 365 days for any year.
+    Nominal,
+}
+
+const NOMINAL_DAYS_PER_MONTH: u64 = 30;
+const NOMINAL_DAYS_PER_YEAR: u64 = 365;
+
+const fn is_calendar_month(atom: Atom) -> bool {
+    matches!(
+        atom,
+        Atom::Month | Atom::MeanJulianMonth | Atom::MeanGregorianMonth | Atom::SynodalMonth
+    )
+}
+
+const fn is_calendar_year(atom: Atom) -> bool {
+    matches!(
+        atom,
+        Atom::Year | Atom::MeanJulianYear | Atom::MeanGregorianYear | Atom::TropicalYear
+    )
+}
+
+impl Measurement {
+    /// Converts `self` to a `std::time::Duration`. `self` must be a measure of
+    /// time (ex. `s`, `h`, `wk`); `policy` determines how months and years are
+    /// handled.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IncompatibleUnitTypes` if `self` isn't a measure of time.
+    /// * `Error::AmbiguousCalendarUnit` if `self` uses months or years and
+    ///   `policy` is `CalendarPolicy::Reject`.
+    /// * `Error::Overflow` if `policy` is `CalendarPolicy::Nominal` and a
+    ///   month or year term's factor is too large to be counted in days.
+    /// * `Error::InvalidDuration` if the value is negative, or too large for a
+    ///   `Duration`.
+    ///
+    pub fn to_duration(&self, policy: CalendarPolicy) -> Result<Duration, Error> {
+        let seconds = self.to_seconds(policy)?;
+
+        Duration::try_from_secs_f64(seconds).map_err(|_| Error::InvalidDuration { seconds })
+    }
+
+    /// Converts `self` to a `chrono::Duration`. Unlike `to_duration()`, this
+    /// allows negative values.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IncompatibleUnitTypes` if `self` isn't a measure of time.
+    /// * `Error::AmbiguousCalendarUnit` if `self` uses months or years and
+    ///   `policy` is `CalendarPolicy::Reject`.
+    /// * `Error::Overflow` if `policy` is `CalendarPolicy::Nominal` and a
+    ///   month or year term's factor is too large to be counted in days.
+    /// * `Error::InvalidDuration` if the value is too large for a
+    ///   `chrono::Duration`.
+    ///
+    #[cfg(feature = "chrono")]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn to_chrono_duration(&self, policy: CalendarPolicy) -> Result<chrono::Duration, Error> {
+        let seconds = self.to_seconds(policy)?;
+        let invalid = || Error::InvalidDuration { seconds };
+
+        if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+            return Err(invalid());
+        }
+
+        let whole_seconds = seconds.trunc();
+        let nanoseconds = ((seconds - whole_seconds) * 1e9).round();
+
+        chrono::Duration::try_seconds(whole_seconds as i64)
+            .and_then(|duration| {
+                duration.checked_add(&chrono::Duration::nanoseconds(nanoseconds as i64))
+            })
+            .ok_or_else(invalid)
+    }
+
+    fn to_seconds(&self, policy: CalendarPolicy) -> Result<f64, Error> {
+        if self.composition() != composition::TIME {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit().expression(),
+                rhs: "s".to_string(),
+            });
+        }
+
+        let uses_calendar_atoms = self
+            .unit()
+            .terms()
+            .iter()
+            .filter_map(|term| term.atom)
+            .any(|atom| is_calendar_month(atom) || is_calendar_year(atom));
+
+        let unit = match (uses_calendar_atoms, policy) {
+            (false, _) | (true, CalendarPolicy::Mean) => self.unit().clone(),
+            (true, CalendarPolicy::Reject) => {
+                return Err(Error::AmbiguousCalendarUnit {
+                    unit: self.unit().expression(),
+                });
+            }
+            (true, CalendarPolicy::Nominal) => nominal_unit(self.unit())?,
+        };
+
+        Self::new(self.value(), unit)
+            .convert_to(&second_unit())
+            .map(|converted| converted.value())
+    }
+}
+
+/// Replaces months and years in `unit` with their nominal number of days.
+/// Returns an `Error::Overflow` if a term's factor times its number of days
+/// doesn't fit in a factor.
+///
+fn nominal_unit(unit: &Unit) -> Result<Unit, Error> {
+    let terms = unit
+        .terms()
+        .iter()
+        .map(|term| {
+            let days = match term.atom {
+                Some(atom) if is_calendar_month(atom) => NOMINAL_DAYS_PER_MONTH,
+                Some(atom) if is_calendar_year(atom) => NOMINAL_DAYS_PER_YEAR,
+                _ => return Ok(term.clone()),
+            };
+
+            let factor =
+                term.factor
+                    .unwrap_or(1)
+                    .checked_mul(days)
+                    .ok_or_else(|| Error::Overflow {
+                        term: term.to_string(),
+                    })?;
+
+            let mut term = term.clone();
+            term.atom = Some(Atom::Day);
+            term.factor = Some(factor);
+            Ok(term)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Unit::new(terms))
+}
+
+/// Creates a `Measurement` in seconds.
+///
+impl From<Duration> for Measurement {
+    fn from(duration: Duration) -> Self {
+        Self::new(duration.as_secs_f64(), second_unit())
+    }
+}
+
+/// Uses `CalendarPolicy::Reject`; use `Measurement::to_duration()` for
+/// converting months or years.
+///
+impl TryFrom<&Measurement> for Duration {
+    type Error = Error;
+
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        measurement.to_duration(CalendarPolicy::Reject)
+    }
+}
+
+/// Creates a `Measurement` in seconds.
+///
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Measurement {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(duration: chrono::Duration) -> Self {
+        let seconds =
+            duration.num_seconds() as f64 + f64::from(duration.subsec_nanos()) / 1_000_000_000.0;
+
+        Self::new(seconds, second_unit())
+    }
+}
+
+/// Uses `CalendarPolicy::Reject`; use `Measurement::to_chrono_duration()` for
+/// converting months or years.
+///
+#[cfg(feature = "chrono")]
+impl TryFrom<&Measurement> for chrono::Duration {
+    type Error = Error;
+
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        measurement.to_chrono_duration(CalendarPolicy::Reject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_to_duration() {
+        let expectations = [
+            (measurement(90.0, "s"), Duration::from_secs(90)),
+            (measurement(1500.0, "ms"), Duration::from_millis(1500)),
+            (measurement(2.5, "min"), Duration::from_secs(150)),
+            (measurement(2.0, "h"), Duration::from_secs(7200)),
+            (measurement(1.0, "d"), Duration::from_secs(86_400)),
+            (measurement(2.0, "wk"), Duration::from_secs(1_209_600)),
+            (measurement(3.0, "h.d/d"), Duration::from_secs(10_800)),
+        ];
+
+        for (measurement, expected) in expectations {
+            assert_eq!(
+                measurement.to_duration(CalendarPolicy::Reject).unwrap(),
+                expected
+            );
+            assert_eq!(Duration::try_from(&measurement).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn validate_to_duration_errors() {
+        assert!(matches!(
+            measurement(1.0, "m").to_duration(CalendarPolicy::Mean),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+        assert!(matches!(
+            measurement(1.0, "/s").to_duration(CalendarPolicy::Mean),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+        assert!(matches!(
+            measurement(-1.0, "s").to_duration(CalendarPolicy::Mean),
+            Err(Error::InvalidDuration { .. })
+        ));
+        assert_eq!(
+            measurement(1.0, "a_g").to_duration(CalendarPolicy::Reject),
+            Err(Error::AmbiguousCalendarUnit {
+                unit: "a_g".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn validate_calendar_policies() {
+        let month = measurement(1.0, "mo_j");
+        assert_eq!(
+            month.to_duration(CalendarPolicy::Mean).unwrap(),
+            Duration::from_secs(2_629_800)
+        );
+        assert_eq!(
+            month.to_duration(CalendarPolicy::Nominal).unwrap(),
+            Duration::from_secs(2_592_000)
+        );
+
+        let year = measurement(2.0, "a_g");
+        assert_eq!(
+            year.to_duration(CalendarPolicy::Mean).unwrap(),
+            Duration::from_secs(63_113_904)
+        );
+        assert_eq!(
+            year.to_duration(CalendarPolicy::Nominal).unwrap(),
+            Duration::from_secs(63_072_000)
+        );
+    }
+
+    #[test]
+    fn validate_nominal_overflow() {
+        let months = measurement(1.0, "18446744073709551615mo");
+
+        assert_eq!(
+            months.to_duration(CalendarPolicy::Nominal).unwrap_err(),
+            Error::Overflow {
+                term: "18446744073709551615mo".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_from_duration() {
+        let measurement = Measurement::from(Duration::from_millis(2500));
+        assert_eq!(measurement, Measurement::try_new(2.5, "s").unwrap());
+        assert_eq!(measurement.unit().expression(), "s");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn validate_chrono_duration() {
+        let duration = measurement(-1.5, "h")
+            .to_chrono_duration(CalendarPolicy::Reject)
+            .unwrap();
+        assert_eq!(duration, chrono::Duration::minutes(-90));
+
+        let duration = chrono::Duration::try_from(&measurement(250.0, "ms")).unwrap();
+        assert_eq!(duration, chrono::Duration::milliseconds(250));
+
+        assert_eq!(
+            Measurement::from(chrono::Duration::milliseconds(-2500)),
+            Measurement::try_new(-2.5, "s").unwrap()
+        );
+        assert_eq!(
+            measurement(1.0, "a").to_chrono_duration(CalendarPolicy::Nominal),
+            Ok(chrono::Duration::days(365))
+        );
+    }
+}
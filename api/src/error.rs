@@ -8,8 +8,47 @@ pub enum Error {
     IncompatibleUnitTypes { lhs: String, rhs: String },
 
     #[error(transparent)]
-    ParsingFailed(#[from] ParserError),
+    ParsingFailed(ParserError),
+
+    #[error("Units measure different kinds of quantity: {lhs:?}, {rhs:?}")]
+    IncompatibleKinds { lhs: String, rhs: String },
 
     #[error("Operation caused a divide by 0")]
     DivideByZero,
+
+    #[error("No exact {code_system} mapping for {value:?}")]
+    NoExactMapping { code_system: String, value: String },
+
+    #[error("Integer overflow in term: {term}")]
+    Overflow { term: String },
+
+    #[error("Unable to parse number: {0:?}")]
+    InvalidNumber(String),
+
+    #[error("{unit:?} has no fixed length in a calendar")]
+    AmbiguousCalendarUnit { unit: String },
+
+    #[error("Can't represent {seconds} seconds as a duration")]
+    InvalidDuration { seconds: f64 },
+
+    #[error("Can't aggregate an empty set of measurements")]
+    EmptyAggregate,
+
+    #[error("Invalid annotation rule: 1 {lhs} = {rhs}")]
+    InvalidAnnotationRule { lhs: String, rhs: String },
+
+    #[error("Unable to aggregate item {index}: {source}")]
+    AggregateItem { index: usize, source: Box<Self> },
+}
+
+/// Overflows are reported as `Error::Overflow`, regardless of whether they were
+/// found while parsing or while operating on parsed units.
+///
+impl From<ParserError> for Error {
+    fn from(error: ParserError) -> Self {
+        match error {
+            ParserError::Overflow { term } => Self::Overflow { term },
+            error => Self::ParsingFailed(error),
+        }
+    }
 }
@@ -0,0 +1,302 @@
+//! Types that describe, step by step, how a `Measurement` gets converted from
+//! one `Unit` to another.
+//!
+//! Use `Measurement::explain_conversion()` to build a `ConversionTrace`; its
+//! `Display` implementation renders the trace as text.
+//!
+use crate::{
+    parser::{Atom, Prefix, Term, UcumSymbol},
+    reducible::Reducible,
+    ucum_unit::UcumUnit,
+    unit::Unit,
+};
+use std::fmt;
+
+/// How a `Term` reduces to its scalar value: `(factor * prefix * atom) ^ exponent`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermTrace {
+    /// The `Term`, as it'd be written in a unit expression.
+    pub term: String,
+    pub factor: Option<u64>,
+    pub prefix: Option<PrefixTrace>,
+    pub atom: Option<AtomTrace>,
+    pub exponent: i32,
+
+    /// The resulting scalar value of the whole `Term`.
+    pub scalar: f64,
+}
+
+impl TermTrace {
+    pub(crate) fn new(term: &Term) -> Self {
+        Self {
+            term: term.to_string(),
+            factor: term.factor,
+            prefix: term.prefix.map(PrefixTrace::new),
+            atom: term.atom.map(AtomTrace::new),
+            exponent: term.exponent.unwrap_or(1),
+            scalar: term.scalar(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrefixTrace {
+    pub prefix: Prefix,
+
+    /// The multiplier the `Prefix` applies (ex. `0.01` for `c`).
+    pub value: f64,
+}
+
+impl PrefixTrace {
+    fn new(prefix: Prefix) -> Self {
+        Self {
+            prefix,
+            value: prefix.definition_value(),
+        }
+    }
+}
+
+/// How an `Atom` is defined, down to the base units.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtomTrace {
+    pub atom: Atom,
+
+    /// The numeric part of the `Atom`'s definition (ex. `2.54` for `[in_i]`,
+    /// which is defined as `2.54 cm`).
+    pub definition_value: f64,
+
+    /// The unit part of the `Atom`'s definition (ex. `cm` for `[in_i]`).
+    pub definition_expression: String,
+
+    /// `true` if the `Atom` is converted using a special (non-linear)
+    /// function instead of its definition's value.
+    pub is_special: bool,
+
+    /// The traces of the `Term`s the `Atom` is defined in. This is empty for
+    /// base units and for `Atom`s that are defined as a pure number.
+    pub definition: Vec<TermTrace>,
+
+    /// The resulting scalar value of the `Atom`.
+    pub scalar: f64,
+}
+
+impl AtomTrace {
+    fn new(atom: Atom) -> Self {
+        let definition = atom.definition();
+
+        let terms = if definition.is_unity() {
+            Vec::new()
+        } else {
+            definition.terms().iter().map(TermTrace::new).collect()
+        };
+
+        Self {
+            atom,
+            definition_value: definition.value(),
+            definition_expression: Unit::new(definition.terms().clone()).expression(),
+            is_special: definition.is_special(),
+            definition: terms,
+            scalar: atom.reduce_value(1.0),
+        }
+    }
+}
+
+/// How a whole `Unit` reduces to its scalar value.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitTrace {
+    pub expression: String,
+    pub terms: Vec<TermTrace>,
+    pub scalar: f64,
+}
+
+impl UnitTrace {
+    pub(crate) fn new(unit: &Unit) -> Self {
+        Self {
+            expression: unit.expression(),
+            terms: unit.terms().iter().map(TermTrace::new).collect(),
+            scalar: unit.scalar(),
+        }
+    }
+}
+
+/// The direction a special unit's function is applied in.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpecialDirection {
+    /// Converts a value in the special unit to its value in base units (ex.
+    /// `Cel` -> `K`).
+    ToBase,
+
+    /// Converts a value in base units to its value in the special unit (ex.
+    /// `K` -> `Cel`).
+    FromBase,
+}
+
+/// One application of a special unit's conversion function.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecialStep {
+    pub unit: String,
+    pub direction: SpecialDirection,
+    pub input: f64,
+    pub output: f64,
+}
+
+/// How the source value was turned into the destination value.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConversionMethod {
+    /// Neither unit is special: the result is `value * factor`, where `factor`
+    /// is the source unit's scalar divided by the destination unit's scalar.
+    Linear { factor: f64 },
+
+    /// At least one unit is special: the value is passed through each unit's
+    /// special function.
+    Special { steps: Vec<SpecialStep> },
+}
+
+/// A structured explanation of a single conversion.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionTrace {
+    pub value: f64,
+    pub source: UnitTrace,
+    pub destination: UnitTrace,
+    pub method: ConversionMethod,
+    pub result: f64,
+}
+
+impl fmt::Display for ConversionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Converting {} {} to {}",
+            self.value, self.source.expression, self.destination.expression
+        )?;
+
+        write_unit_trace(f, "Source", &self.source)?;
+        write_unit_trace(f, "Destination", &self.destination)?;
+
+        match &self.method {
+            ConversionMethod::Linear { factor } => {
+                writeln!(
+                    f,
+                    "Factor: {} / {} = {}",
+                    self.source.scalar, self.destination.scalar, factor
+                )?;
+                writeln!(f, "Result: {} * {} = {}", self.value, factor, self.result)?;
+            }
+            ConversionMethod::Special { steps } => {
+                for step in steps {
+                    let direction = match step.direction {
+                        SpecialDirection::ToBase => "to base units",
+                        SpecialDirection::FromBase => "from base units",
+                    };
+
+                    writeln!(
+                        f,
+                        "Special function of {} ({}): {} -> {}",
+                        step.unit, direction, step.input, step.output
+                    )?;
+                }
+
+                writeln!(f, "Result: {}", self.result)?;
+            }
+        }
+
+        write!(f, "= {} {}", self.result, self.destination.expression)
+    }
+}
+
+fn write_unit_trace(f: &mut fmt::Formatter<'_>, label: &str, trace: &UnitTrace) -> fmt::Result {
+    writeln!(
+        f,
+        "{}: {} (scalar {})",
+        label, trace.expression, trace.scalar
+    )?;
+
+    for term in &trace.terms {
+        write_term_trace(f, term, 1)?;
+    }
+
+    Ok(())
+}
+
+fn write_term_trace(f: &mut fmt::Formatter<'_>, trace: &TermTrace, depth: usize) -> fmt::Result {
+    let indent = "  ".repeat(depth);
+    let mut parts = Vec::new();
+
+    if let Some(factor) = trace.factor {
+        parts.push(format!("factor {}", factor));
+    }
+
+    if let Some(prefix) = &trace.prefix {
+        parts.push(format!("prefix {} ({})", prefix.prefix, prefix.value));
+    }
+
+    if let Some(atom) = &trace.atom {
+        parts.push(format!("{} ({})", atom.atom, atom.scalar));
+    }
+
+    let product = if parts.is_empty() {
+        String::from("1")
+    } else {
+        parts.join(" * ")
+    };
+
+    if trace.exponent == 1 {
+        writeln!(
+            f,
+            "{}{}: {} = {}",
+            indent, trace.term, product, trace.scalar
+        )?;
+    } else {
+        writeln!(
+            f,
+            "{}{}: ({})^{} = {}",
+            indent, trace.term, product, trace.exponent, trace.scalar
+        )?;
+    }
+
+    if let Some(atom) = &trace.atom {
+        write_atom_trace(f, atom, depth + 1)?;
+    }
+
+    Ok(())
+}
+
+fn write_atom_trace(f: &mut fmt::Formatter<'_>, trace: &AtomTrace, depth: usize) -> fmt::Result {
+    let indent = "  ".repeat(depth);
+
+    if trace.is_special {
+        writeln!(
+            f,
+            "{}{} is special: converted by function, in terms of {}",
+            indent, trace.atom, trace.definition_expression
+        )?;
+    } else if trace.definition.is_empty() {
+        #[allow(clippy::float_cmp)]
+        if trace.definition_value == 1.0 {
+            writeln!(f, "{}{} is a base unit", indent, trace.atom)?;
+        } else {
+            writeln!(f, "{}{} = {}", indent, trace.atom, trace.definition_value)?;
+        }
+
+        return Ok(());
+    } else {
+        writeln!(
+            f,
+            "{}{} = {} {}",
+            indent, trace.atom, trace.definition_value, trace.definition_expression
+        )?;
+    }
+
+    for term in &trace.definition {
+        write_term_trace(f, term, depth + 1)?;
+    }
+
+    Ok(())
+}
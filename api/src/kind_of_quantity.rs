@@ -0,0 +1,186 @@
+//! Kind-of-quantity (`Property`) awareness for units.
+//!
+//! UCUM distinguishes some units that share a dimension by the kind of
+//! quantity they measure: `Hz` measures frequency while `Bq`
+//! measures radioactivity, and `Gy` measures an energy dose while `Sv` measures
+//! a dose equivalent.
+//!
+//! The `Composition`-based `IsCompatibleWith` implementations can't tell these
+//! apart; this module adds the pieces needed to do so. See `Strict` for
+//! comparing, converting, and doing arithmetic with kind-of-quantity checks.
+//!
+use crate::{
+    composition,
+    parser::{Atom, Composable, Composition, Property, Term},
+    Measurement, Unit,
+};
+
+/// `Property`s that share a dimension with some other, different kind of
+/// quantity, and thus must not be mixed in strict mode.
+///
+const DISTINCT_KINDS: [Property; 5] = [
+    Property::Frequency,
+    Property::Radioactivity,
+    Property::SignalTransmissionRate,
+    Property::EnergyDose,
+    Property::DoseEquivalent,
+];
+
+/// Describes the kind of quantity that a type measures.
+///
+pub trait KindOfQuantity {
+    /// The `Property` the type measures. For simple units this is the
+    /// `Property` of the `Atom`; for compound units it's inferred from the
+    /// dimension (see `property_for_composition()`). `None` means the kind
+    /// can't be determined.
+    ///
+    fn kind_of_quantity(&self) -> Option<Property>;
+}
+
+impl KindOfQuantity for Atom {
+    fn kind_of_quantity(&self) -> Option<Property> {
+        Some(self.property())
+    }
+}
+
+impl KindOfQuantity for Term {
+    fn kind_of_quantity(&self) -> Option<Property> {
+        match (self.atom, self.exponent) {
+            (Some(atom), None | Some(1)) => atom.kind_of_quantity(),
+            _ => property_for_composition(self.composition()),
+        }
+    }
+}
+
+impl KindOfQuantity for Unit {
+    fn kind_of_quantity(&self) -> Option<Property> {
+        match self.terms().as_slice() {
+            [term] => term.kind_of_quantity(),
+            _ => property_for_composition(self.composition()),
+        }
+    }
+}
+
+impl KindOfQuantity for Measurement {
+    fn kind_of_quantity(&self) -> Option<Property> {
+        self.unit().kind_of_quantity()
+    }
+}
+
+/// Infers the kind of quantity for well-known dimensions.
+///
+/// Returns `None` for dimensions that aren't well-known, or that are shared by
+/// more than one kind of quantity (ex. `T-1` could be a frequency or a
+/// radioactivity).
+///
+/// ```rust
+/// use wise_units::{
+///     composition, kind_of_quantity::property_for_composition, Composition, Property,
+/// };
+///
+/// assert_eq!(property_for_composition(composition::VELOCITY), Some(Property::Velocity));
+/// assert_eq!(property_for_composition(Composition::new_time(-1)), None);
+/// ```
+///
+#[must_use]
+pub const fn property_for_composition(composition: Composition) -> Option<Property> {
+    let property = match composition {
+        composition::LENGTH => Property::Length,
+        composition::AREA => Property::Area,
+        composition::VOLUME => Property::Volume,
+        composition::MASS => Property::Mass,
+        composition::TIME => Property::Time,
+        composition::TEMPERATURE => Property::Temperature,
+        composition::ELECTRIC_CHARGE => Property::ElectricCharge,
+        composition::LUMINOUS_INTENSITY => Property::LuminousIntensity,
+        composition::VELOCITY => Property::Velocity,
+        composition::ACCELERATION => Property::Acceleration,
+        composition::FORCE => Property::Force,
+        composition::PRESSURE => Property::Pressure,
+        composition::POWER => Property::Power,
+        composition::DYNAMIC_VISCOSITY => Property::DynamicViscosity,
+        composition::KINEMATIC_VISCOSITY => Property::KinematicViscosity,
+        _ => return None,
+    };
+
+    Some(property)
+}
+
+/// Checks that `lhs` and `rhs` don't measure different kinds of quantity.
+/// This only compares the atoms whose kind is shared with some other kind of
+/// the same dimension (ex. `Hz` and `Bq`); units that don't use any such atoms
+/// (ex. `s-1` or `J/kg`) are considered generic, and match either kind.
+///
+pub(crate) fn kinds_match(lhs: &Unit, rhs: &Unit) -> bool {
+    let lhs = distinct_kinds(lhs);
+    let rhs = distinct_kinds(rhs);
+
+    lhs.is_empty() || rhs.is_empty() || lhs == rhs
+}
+
+/// Checks that `lhs` and `rhs` measure exactly the same kinds of quantity.
+/// Unlike `kinds_match()`, a generic unit (ex. `J/kg`) only has the same kinds
+/// as another generic unit, so this is transitive.
+///
+pub(crate) fn kinds_equal(lhs: &Unit, rhs: &Unit) -> bool {
+    distinct_kinds(lhs) == distinct_kinds(rhs)
+}
+
+fn distinct_kinds(unit: &Unit) -> Vec<(Property, i32)> {
+    let mut kinds: Vec<(Property, i32)> = Vec::new();
+
+    for term in unit.terms() {
+        let property = match term.atom {
+            Some(atom) if DISTINCT_KINDS.contains(&atom.property()) => atom.property(),
+            _ => continue,
+        };
+        let exponent = term.exponent.unwrap_or(1);
+
+        match kinds.iter_mut().find(|(p, _)| *p == property) {
+            Some((_, e)) => *e += exponent,
+            None => kinds.push((property, exponent)),
+        }
+    }
+
+    kinds.retain(|(_, exponent)| *exponent != 0);
+    kinds.sort_unstable();
+    kinds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_kind_of_quantity() {
+        assert_eq!(unit("kHz").kind_of_quantity(), Some(Property::Frequency));
+        assert_eq!(unit("Bq").kind_of_quantity(), Some(Property::Radioactivity));
+        assert_eq!(unit("km/h").kind_of_quantity(), Some(Property::Velocity));
+        assert_eq!(unit("m2").kind_of_quantity(), Some(Property::Area));
+        assert_eq!(unit("s-1").kind_of_quantity(), None);
+        assert_eq!(unit("m/g").kind_of_quantity(), None);
+
+        let measurement = Measurement::try_new(1.0, "Gy").unwrap();
+        assert_eq!(measurement.kind_of_quantity(), Some(Property::EnergyDose));
+    }
+
+    #[test]
+    fn validate_kinds_match() {
+        assert!(!kinds_match(&unit("Hz"), &unit("Bq")));
+        assert!(!kinds_match(&unit("Gy"), &unit("Sv")));
+        assert!(!kinds_match(&unit("mGy/h"), &unit("uSv/h")));
+        assert!(!kinds_match(&unit("Bd"), &unit("Hz")));
+
+        assert!(kinds_match(&unit("Bq"), &unit("Ci")));
+        assert!(kinds_match(&unit("Sv"), &unit("REM")));
+        assert!(kinds_match(&unit("Hz"), &unit("s-1")));
+        assert!(kinds_match(&unit("Gy"), &unit("J/kg")));
+        assert!(kinds_match(&unit("L"), &unit("[gal_us]")));
+        assert!(kinds_match(&unit("Gy.s/s"), &unit("Gy")));
+    }
+}
@@ -0,0 +1,519 @@
+//! Opt-in parsing of unit expressions as people tend to type (or paste) them.
+//!
+//! `Unit::from_str()` only accepts UCUM codes. `parse()` first normalizes
+//! common typographic forms into UCUM codes, then parses the result the same
+//! way `Unit::from_str()` does:
+//!
+//! * micro signs and Greek mus in front of a metric unit (`µg`, `μg`) become
+//!   the `u` prefix (`ug`)
+//! * superscript exponents (`m²`, `s⁻¹`) become digits (`m2`, `s-1`)
+//! * middle dots and multiplication signs (`kg·m`, `kg×m`) become `.`
+//! * whitespace around operators is removed (`mg / dL`); whitespace between
+//!   two symbols becomes `.` (`kg m`)
+//! * the print symbols `°C`, `°F`, `°`, `Ω` and `Å`, optionally with a prefix
+//!   (`kΩ`), become their atom's code; other print symbols are left as is,
+//!   since many of them are also plain letters (ex. `k` for the Boltzmann
+//!   constant)
+//!
+//! Text inside annotations is left as is. Each change is reported as a
+//! `Normalization`, with the `Span` it came from in the input.
+//!
+//! ```rust
+//! use wise_units::lenient;
+//!
+//! let parsed = lenient::parse("kg·m/s²").unwrap();
+//! assert_eq!(parsed.expression, "kg.m/s2");
+//! assert_eq!(parsed.unit.expression(), "kg.m/s2");
+//! assert_eq!(parsed.normalizations.len(), 2);
+//!
+//! let parsed = lenient::parse("°C").unwrap();
+//! assert_eq!(parsed.unit.expression(), "Cel");
+//! ```
+//!
+use crate::{
+    parser::{ast::Span, Atom, Prefix},
+    ucum_unit::UcumUnit,
+    Error, UcumSymbol, Unit,
+};
+use std::str::FromStr;
+
+/// A part of the input that was rewritten before parsing.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Normalization {
+    /// Where `original` is in the input.
+    pub span: Span,
+    pub original: String,
+    pub replacement: String,
+}
+
+/// The result of a lenient parse.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct LenientParse {
+    pub unit: Unit,
+
+    /// The normalized expression that `unit` was parsed from.
+    pub expression: String,
+
+    /// What was normalized, in the order it appears in the input. This is
+    /// empty if the input was already a valid UCUM expression.
+    pub normalizations: Vec<Normalization>,
+}
+
+/// Normalizes `input` into a UCUM expression and parses it.
+///
+/// # Errors
+///
+/// Returns an `Error::ParsingFailed` if the normalized expression doesn't
+/// parse.
+///
+pub fn parse(input: &str) -> Result<LenientParse, Error> {
+    let (expression, normalizations) = normalize(input);
+    let unit = Unit::from_str(&expression)?;
+
+    Ok(LenientParse {
+        unit,
+        expression,
+        normalizations,
+    })
+}
+
+impl Unit {
+    /// Like `Unit::from_str()`, but first normalizes typographic forms (ex.
+    /// `μg/m³`, `°C`) into UCUM codes. Use `lenient::parse()` to find out what
+    /// was normalized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::ParsingFailed` if the normalized expression doesn't
+    /// parse.
+    ///
+    pub fn from_str_lenient(input: &str) -> Result<Self, Error> {
+        parse(input).map(|parsed| parsed.unit)
+    }
+}
+
+/// The print symbols that are rewritten to UCUM codes, and their atoms.
+///
+const PRINT_SYMBOLS: [(&str, Atom); 5] = [
+    ("°C", Atom::DegreeCelsius),
+    ("°F", Atom::DegreeFahrenheit),
+    ("°", Atom::Degree),
+    ("Ω", Atom::Ohm),
+    ("Å", Atom::Angstrom),
+];
+
+const fn is_operator(c: char) -> bool {
+    matches!(c, '.' | '/' | '(' | ')')
+}
+
+const fn is_multiplication_sign(c: char) -> bool {
+    matches!(c, '·' | '⋅' | '∙' | '•' | '×')
+}
+
+const fn superscript_digit(c: char) -> Option<char> {
+    match c {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        _ => None,
+    }
+}
+
+struct Normalizer<'a> {
+    input: &'a str,
+    output: String,
+    normalizations: Vec<Normalization>,
+}
+
+impl Normalizer<'_> {
+    /// Appends `replacement` for `input[start..end]`, recording a
+    /// `Normalization` if they differ.
+    ///
+    fn push(&mut self, start: usize, end: usize, replacement: &str) {
+        let original = &self.input[start..end];
+
+        if original != replacement {
+            self.normalizations.push(Normalization {
+                span: Span { start, end },
+                original: original.to_string(),
+                replacement: replacement.to_string(),
+            });
+        }
+
+        self.output.push_str(replacement);
+    }
+}
+
+fn normalize(input: &str) -> (String, Vec<Normalization>) {
+    let mut normalizer = Normalizer {
+        input,
+        output: String::with_capacity(input.len()),
+        normalizations: Vec::new(),
+    };
+
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c == '{' {
+            // Annotations are copied as they are.
+            let mut end = input.len();
+
+            for (i, c) in chars.by_ref() {
+                if c == '}' {
+                    end = i + c.len_utf8();
+                    break;
+                }
+            }
+
+            normalizer.push(start, end, &input[start..end]);
+        } else if is_operator(c) {
+            normalizer.push(
+                start,
+                start + c.len_utf8(),
+                &input[start..start + c.len_utf8()],
+            );
+        } else if is_multiplication_sign(c) {
+            normalizer.push(start, start + c.len_utf8(), ".");
+        } else if c.is_whitespace() {
+            let mut end = start + c.len_utf8();
+
+            while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_whitespace()) {
+                end = i + c.len_utf8();
+            }
+
+            // Whitespace between two symbols separates them; anywhere else,
+            // it's just padding.
+            let follows_symbol = normalizer
+                .output
+                .chars()
+                .last()
+                .is_some_and(|c| !is_operator(c));
+            let precedes_symbol = chars
+                .peek()
+                .is_some_and(|&(_, c)| c != '{' && !is_operator(c) && !is_multiplication_sign(c));
+
+            if follows_symbol && precedes_symbol {
+                normalizer.push(start, end, ".");
+            } else {
+                normalizer.push(start, end, "");
+            }
+        } else {
+            // A symbol, possibly with an exponent; brackets (ex. `[in_i]`) may
+            // contain characters that are otherwise operators.
+            let mut depth = usize::from(c == '[');
+            let mut end = start + c.len_utf8();
+
+            while let Some((i, c)) = chars.next_if(|&(_, c)| {
+                depth > 0
+                    || !(c == '{'
+                        || is_operator(c)
+                        || is_multiplication_sign(c)
+                        || c.is_whitespace())
+            }) {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth = depth.saturating_sub(1),
+                    _ => (),
+                }
+
+                end = i + c.len_utf8();
+            }
+
+            normalize_symbol(&mut normalizer, start, end);
+        }
+    }
+
+    (normalizer.output, normalizer.normalizations)
+}
+
+/// Normalizes a symbol with an optional exponent (ex. `m²`) at
+/// `input[start..end]`.
+///
+fn normalize_symbol(normalizer: &mut Normalizer<'_>, start: usize, end: usize) {
+    let symbol = &normalizer.input[start..end];
+    let exponent_start = start + exponent_offset(symbol);
+
+    if exponent_start == start {
+        let base = normalize_base(symbol);
+        normalizer.push(start, end, &base);
+        return;
+    }
+
+    let exponent = &normalizer.input[exponent_start..end];
+    let base = &normalizer.input[start..exponent_start];
+
+    // `10⁶` is the UCUM `10*6`, not the factor `106`.
+    let base = if base == "10" && !exponent.is_ascii() {
+        "10*".to_string()
+    } else {
+        normalize_base(base)
+    };
+    normalizer.push(start, exponent_start, &base);
+
+    let exponent: String = exponent
+        .chars()
+        .map(|c| match c {
+            '⁻' => '-',
+            '⁺' => '+',
+            c => superscript_digit(c).unwrap_or(c),
+        })
+        .collect();
+    normalizer.push(exponent_start, end, &exponent);
+}
+
+/// The byte offset of the trailing exponent in `symbol`, or `0` if there
+/// isn't one (or if `symbol` is only a number). Superscript exponents are
+/// split off of any ASCII digits before them (ex. `10` and `⁶` in `10⁶`).
+///
+fn exponent_offset(symbol: &str) -> usize {
+    let superscript_offset = trailing_offset(
+        symbol,
+        |c| superscript_digit(c).is_some(),
+        |c| matches!(c, '⁻' | '⁺'),
+    );
+
+    let offset = if superscript_offset < symbol.len() {
+        superscript_offset
+    } else {
+        trailing_offset(symbol, |c| c.is_ascii_digit(), |c| matches!(c, '-' | '+'))
+    };
+
+    if offset == symbol.len() {
+        0
+    } else {
+        offset
+    }
+}
+
+/// The byte offset of the trailing run of `is_digit` characters in `symbol`,
+/// including a sign before them.
+///
+fn trailing_offset<D, S>(symbol: &str, is_digit: D, is_sign: S) -> usize
+where
+    D: Fn(char) -> bool,
+    S: Fn(char) -> bool,
+{
+    let mut offset = symbol.len();
+    let mut chars = symbol.char_indices().rev().peekable();
+
+    while let Some((i, _)) = chars.next_if(|&(_, c)| is_digit(c)) {
+        offset = i;
+    }
+
+    if offset < symbol.len() {
+        if let Some((i, _)) = chars.next_if(|&(_, c)| is_sign(c)) {
+            offset = i;
+        }
+    }
+
+    offset
+}
+
+/// Normalizes a symbol without an exponent into a UCUM code, if it isn't one
+/// already.
+///
+fn normalize_base(base: &str) -> String {
+    if is_valid(base) {
+        return base.to_string();
+    }
+
+    let mut normalized = String::with_capacity(base.len());
+
+    for c in base.chars() {
+        match c {
+            '\u{2126}' => normalized.push('Ω'),
+            '\u{212B}' => normalized.push('Å'),
+            '℃' => normalized.push_str("°C"),
+            '℉' => normalized.push_str("°F"),
+            c => normalized.push(c),
+        }
+    }
+
+    if is_valid(&normalized) {
+        return normalized;
+    }
+
+    if let Some(atom) = atom_from_print_symbol(&normalized) {
+        return atom.primary_code().to_string();
+    }
+
+    for prefix in Prefix::iter() {
+        if let Some(rest) = normalized.strip_prefix(prefix.primary_code()) {
+            if let Some(atom) = atom_from_print_symbol(rest).filter(UcumUnit::is_metric) {
+                return format!("{}{}", prefix.primary_code(), atom.primary_code());
+            }
+        }
+    }
+
+    normalized
+        .strip_prefix(['µ', 'μ'])
+        .and_then(micro_prefixed)
+        .unwrap_or(normalized)
+}
+
+/// `u` followed by `rest` (or by the code for `rest`, if it's a print symbol),
+/// if that's the micro prefix on a metric atom. Anywhere else, a micro sign
+/// isn't a prefix (ex. in `μ0`).
+///
+fn micro_prefixed(rest: &str) -> Option<String> {
+    let rest = atom_from_print_symbol(rest).map_or(rest, |atom| atom.primary_code());
+    let code = format!("u{rest}");
+    let unit = Unit::from_str(&code).ok()?;
+
+    let is_micro_prefixed = matches!(
+        unit.terms().as_slice(),
+        [term] if term.prefix == Some(Prefix::Micro)
+            && term.atom.is_some_and(|atom| atom.is_metric())
+            && term.factor.is_none()
+            && term.exponent.is_none()
+    );
+
+    is_micro_prefixed.then_some(code)
+}
+
+fn is_valid(symbol: &str) -> bool {
+    !symbol.is_empty() && Unit::from_str(symbol).is_ok()
+}
+
+/// The `Atom` that `print_symbol` is rewritten to, if it's in `PRINT_SYMBOLS`.
+///
+fn atom_from_print_symbol(print_symbol: &str) -> Option<Atom> {
+    PRINT_SYMBOLS
+        .iter()
+        .find(|(symbol, _)| *symbol == print_symbol)
+        .map(|&(_, atom)| atom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expression(input: &str) -> String {
+        parse(input).unwrap().expression
+    }
+
+    #[test]
+    fn validate_strict_expressions_are_unchanged() {
+        for input in [
+            "m",
+            "kg.m2/s2",
+            "10*3/uL",
+            "mL{total}/d",
+            "[in_i]",
+            "/s",
+            "Cel",
+        ] {
+            let parsed = parse(input).unwrap();
+
+            assert_eq!(parsed.expression, input);
+            assert_eq!(parsed.normalizations, []);
+            assert_eq!(parsed.unit, Unit::from_str(input).unwrap());
+        }
+    }
+
+    #[test]
+    fn validate_micro() {
+        assert_eq!(expression("µg"), "ug");
+        assert_eq!(expression("μg/mL"), "ug/mL");
+        assert_eq!(expression("µmol/L"), "umol/L");
+        assert_eq!(expression("µΩ"), "uOhm");
+        assert_eq!(normalize("μ0").0, "μ0");
+        assert_eq!(normalize("µ").0, "µ");
+        assert!(parse("μ0").is_err());
+    }
+
+    #[test]
+    fn validate_superscripts() {
+        assert_eq!(expression("m²"), "m2");
+        assert_eq!(expression("m³"), "m3");
+        assert_eq!(expression("s⁻¹"), "s-1");
+        assert_eq!(expression("kg·m/s²"), "kg.m/s2");
+        assert_eq!(expression("10*3/µL"), "10*3/uL");
+        assert_eq!(expression("10⁶/L"), "10*6/L");
+        assert_eq!(expression("10*⁶/L"), "10*6/L");
+    }
+
+    #[test]
+    fn validate_print_symbols() {
+        assert_eq!(expression("°C"), "Cel");
+        assert_eq!(expression("°F"), "[degF]");
+        assert_eq!(expression("℃"), "Cel");
+        assert_eq!(expression("Ω"), "Ohm");
+        assert_eq!(expression("kΩ"), "kOhm");
+        assert_eq!(expression("Å"), "Ao");
+        assert_eq!(expression("mg × m"), "mg.m");
+        assert_eq!(expression("mg / dL"), "mg/dL");
+        assert_eq!(expression("kg m"), "kg.m");
+        assert_eq!(expression(" N·m "), "N.m");
+        assert_eq!(expression("°"), "deg");
+    }
+
+    #[test]
+    fn validate_other_print_symbols_are_unchanged() {
+        for input in ["mos", "k", "c", "e", "me", "gn", "at", "ftus"] {
+            let (normalized, normalizations) = normalize(input);
+
+            assert_eq!(normalized, input);
+            assert_eq!(normalizations, []);
+        }
+    }
+
+    #[test]
+    fn validate_annotations_are_unchanged() {
+        assert_eq!(normalize("µg{µ·²}").0, "ug{µ·²}");
+        assert_eq!(expression("m²{a.b/c}"), "m2{a.b/c}");
+    }
+
+    #[test]
+    fn validate_normalization_spans() {
+        let input = "µg·m⁻²";
+        let parsed = parse(input).unwrap();
+
+        assert_eq!(parsed.expression, "ug.m-2");
+        assert_eq!(parsed.unit.expression(), "ug/m2");
+        assert_eq!(
+            parsed.normalizations,
+            [
+                Normalization {
+                    span: Span { start: 0, end: 3 },
+                    original: "µg".to_string(),
+                    replacement: "ug".to_string(),
+                },
+                Normalization {
+                    span: Span { start: 3, end: 5 },
+                    original: "·".to_string(),
+                    replacement: ".".to_string(),
+                },
+                Normalization {
+                    span: Span { start: 6, end: 11 },
+                    original: "⁻²".to_string(),
+                    replacement: "-2".to_string(),
+                },
+            ]
+        );
+
+        for normalization in &parsed.normalizations {
+            assert_eq!(normalization.span.slice(input), normalization.original);
+        }
+    }
+
+    #[test]
+    fn validate_errors() {
+        assert!(parse("[in]").is_err());
+        assert!(parse("foo²").is_err());
+        assert!(Unit::from_str("µg").is_err());
+        assert_eq!(
+            Unit::from_str_lenient("µg").unwrap(),
+            Unit::from_str("ug").unwrap()
+        );
+    }
+}
@@ -21,30 +21,50 @@
 #[macro_use]
 mod macros;
 
+pub mod aggregate;
+pub mod annotation_policy;
+pub mod annotation_rules;
 pub mod as_fraction;
+pub mod catalog;
 pub mod convertible;
+pub mod duration;
 pub mod error;
+pub mod explain;
 pub mod field_eq;
 pub mod invert;
 pub mod is_compatible_with;
+pub mod kind_of_quantity;
+pub mod lenient;
+pub mod lint;
 pub mod measurement;
 pub mod parser;
+pub mod precision;
 pub mod reduce;
+pub mod render;
+pub mod strict;
 pub mod unit;
+pub mod unit_system;
 
 mod reducible;
+mod scoped;
 mod ucum_unit;
+#[cfg(feature = "uom")]
+mod uom;
 
 pub use crate::{
+    annotation_policy::AnnotationPolicy,
     convertible::Convertible,
     error::Error,
     field_eq::FieldEq,
     is_compatible_with::IsCompatibleWith,
+    kind_of_quantity::KindOfQuantity,
     measurement::Measurement,
     parser::{
         composition, Atom, Classification, Composable, Composition, Dimension, Prefix, Property,
         Term, UcumSymbol,
     },
+    strict::Strict,
     ucum_unit::UcumUnit,
     unit::Unit,
+    unit_system::UnitSystem,
 };
@@ -0,0 +1,486 @@
+//! Checks for unit expressions that parse, but are ambiguous or discouraged.
+//!
+//! `lint()` never rejects a unit that would otherwise parse; it returns a list
+//! of `Warning`s, each with the `Span` it applies to and, where there's an
+//! unambiguous way to write the same thing, a suggested rewrite of the whole
+//! expression.
+//!
+//! ```rust
+//! use wise_units::lint::{lint, WarningKind};
+//!
+//! let warnings = lint("mg/kg/d").unwrap();
+//!
+//! assert_eq!(warnings.len(), 1);
+//! assert_eq!(warnings[0].kind, WarningKind::MultipleSlashes);
+//! assert_eq!(warnings[0].suggestion.as_deref(), Some("mg/(kg.d)"));
+//!
+//! assert!(lint("mg/(kg.d)").unwrap().is_empty());
+//! ```
+//!
+use crate::{
+    parser::{
+        ast::{
+            self, walk_annotatable, walk_component, AnnotatableNode, BasicComponentNode,
+            ComponentNode, Expression, OperatorKind, Span, Visitor,
+        },
+        Atom, UcumSymbol,
+    },
+    ucum_unit::UcumUnit,
+    Error, Unit,
+};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// More than one `/` outside of parentheses (ex. `mg/kg/d`). UCUM reads
+    /// these left to right, but readers (and this crate's parser) don't
+    /// always agree on that.
+    MultipleSlashes,
+
+    /// A prefix on an atom that isn't metric (ex. `k[in_i]`).
+    PrefixedNonMetricAtom,
+
+    /// An annotation standing in for a unit (ex. `{tablet}`), which UCUM
+    /// treats as `1`.
+    AnnotationAsUnit,
+
+    /// A special (non-ratio) unit, like `Cel` or `[pH]`, combined with other
+    /// terms, an exponent, or a factor. Special units don't scale linearly,
+    /// so these can't be converted the way they look like they should be.
+    SpecialUnitInCompound,
+
+    /// Both `10*` and `10^` in the same expression.
+    MixedPowersOfTen,
+
+    /// An atom whose use is discouraged (ex. `[ppb]` and `[pptr]`, since
+    /// "billion" and "trillion" mean different things in different
+    /// languages).
+    DeprecatedAtom,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub kind: WarningKind,
+
+    /// The part of the expression the warning applies to.
+    pub span: Span,
+    pub message: String,
+
+    /// The whole expression, rewritten to avoid the warning, if there's an
+    /// unambiguous way to do so.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}..{}: {}",
+            self.span.start, self.span.end, self.message
+        )?;
+
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (suggestion: `{suggestion}`)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks `expression` for constructs that parse, but are ambiguous or
+/// discouraged. The resulting `Warning`s are ordered by where they start in
+/// the expression.
+///
+/// # Errors
+///
+/// Returns an `Error::ParsingFailed` if `expression` doesn't parse.
+///
+pub fn lint(expression: &str) -> Result<Vec<Warning>, Error> {
+    let expression = ast::parse(expression)?;
+    let terms = expression.to_terms();
+
+    let mut linter = Linter {
+        expression: &expression,
+        is_compound: terms
+            .iter()
+            .filter(|term| term.atom.is_some() || term.factor.is_some())
+            .count()
+            > 1,
+        component_has_factor: false,
+        has_star: false,
+        carets: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    linter.check_slashes();
+    linter.visit_expression(&expression);
+    linter.check_powers_of_ten();
+
+    let mut warnings = linter.warnings;
+    warnings.sort_by_key(|warning| warning.span.start);
+
+    Ok(warnings)
+}
+
+struct Linter<'a> {
+    expression: &'a Expression,
+    is_compound: bool,
+    component_has_factor: bool,
+    has_star: bool,
+    carets: Vec<Span>,
+    warnings: Vec<Warning>,
+}
+
+impl Linter<'_> {
+    fn source(&self) -> &str {
+        &self.expression.source
+    }
+
+    /// `self`'s expression with `span` replaced by `replacement`.
+    ///
+    fn rewrite(&self, span: Span, replacement: &str) -> String {
+        let source = self.source();
+
+        format!(
+            "{}{replacement}{}",
+            &source[..span.start],
+            &source[span.end..]
+        )
+    }
+
+    fn check_slashes(&mut self) {
+        let mut numerator = Vec::new();
+        let mut denominator = Vec::new();
+        let mut slashes = 0;
+
+        let mut is_divided = self.expression.leading_slash.is_some();
+        let mut term = &self.expression.term;
+
+        if is_divided {
+            slashes += 1;
+        }
+
+        loop {
+            let component = term.component.span.slice(self.source());
+
+            if is_divided {
+                denominator.push(component);
+            } else {
+                numerator.push(component);
+            }
+
+            match &term.rest {
+                Some((operator, rest)) => {
+                    is_divided = operator.kind == OperatorKind::Slash;
+
+                    if is_divided {
+                        slashes += 1;
+                    }
+
+                    term = rest;
+                }
+                None => break,
+            }
+        }
+
+        if slashes < 2 {
+            return;
+        }
+
+        let denominator = if denominator.len() == 1 {
+            denominator[0].to_string()
+        } else {
+            format!("({})", denominator.join("."))
+        };
+        let suggestion = format!("{}/{denominator}", numerator.join("."));
+
+        let parsed = Unit::new(self.expression.to_terms()).expression();
+
+        self.warnings.push(Warning {
+            kind: WarningKind::MultipleSlashes,
+            span: self.expression.span,
+            message: format!(
+                "`{}` has more than one `/`; UCUM reads it as `{suggestion}`, but wise_units \
+                 currently parses it as `{parsed}`",
+                self.source()
+            ),
+            suggestion: Some(suggestion),
+        });
+    }
+
+    fn check_powers_of_ten(&mut self) {
+        if !self.has_star {
+            return;
+        }
+
+        let carets = std::mem::take(&mut self.carets);
+        let mut suggestion = self.source().to_string();
+
+        for span in carets.iter().rev() {
+            suggestion.replace_range(span.start..span.end, "10*");
+        }
+
+        for span in carets {
+            self.warnings.push(Warning {
+                kind: WarningKind::MixedPowersOfTen,
+                span,
+                message: "`10^` and `10*` are mixed; `10^` only exists for HL7 compatibility"
+                    .to_string(),
+                suggestion: Some(suggestion.clone()),
+            });
+        }
+    }
+
+    fn check_annotatable(&mut self, annotatable: &AnnotatableNode) {
+        let simple_unit = &annotatable.simple_unit;
+        let Some(atom) = simple_unit.atom else {
+            return;
+        };
+
+        // Only rewrite symbols that stand on their own; swapping the symbol for
+        // a product would change what a factor or exponent applies to.
+        let is_standalone = !self.component_has_factor && annotatable.exponent.is_none();
+
+        match atom {
+            Atom::TheNumberTenForArbitraryPowersStar => self.has_star = true,
+            Atom::TheNumberTenForArbitraryPowersCaret => self.carets.push(simple_unit.span),
+            _ => (),
+        }
+
+        if let Some(prefix) = simple_unit.prefix {
+            if !atom.is_metric() {
+                let suggestion = power_of_ten(prefix.definition_value())
+                    .filter(|_| is_standalone)
+                    .map(|exponent| {
+                        self.rewrite(
+                            simple_unit.span,
+                            &format!("10*{exponent}.{}", atom.primary_code()),
+                        )
+                    });
+
+                self.warnings.push(Warning {
+                    kind: WarningKind::PrefixedNonMetricAtom,
+                    span: simple_unit.span,
+                    message: format!(
+                        "`{}` isn't metric, so it shouldn't take the prefix `{}`",
+                        atom.primary_code(),
+                        prefix.primary_code()
+                    ),
+                    suggestion,
+                });
+            }
+        }
+
+        if atom.is_special() && (self.is_compound || !is_standalone) {
+            self.warnings.push(Warning {
+                kind: WarningKind::SpecialUnitInCompound,
+                span: simple_unit.span,
+                message: format!(
+                    "`{}` is a special unit, which doesn't scale linearly; combining it with \
+                     other terms, exponents or factors may not mean what it looks like",
+                    atom.primary_code()
+                ),
+                suggestion: None,
+            });
+        }
+
+        if let Some(replacement) = deprecated_atom_replacement(atom) {
+            let suggestion = (is_standalone && simple_unit.prefix.is_none())
+                .then(|| self.rewrite(simple_unit.span, replacement));
+
+            self.warnings.push(Warning {
+                kind: WarningKind::DeprecatedAtom,
+                span: simple_unit.span,
+                message: format!(
+                    "`{}` is discouraged, since its name means different things in different \
+                     languages; use `{replacement}`",
+                    atom.primary_code()
+                ),
+                suggestion,
+            });
+        }
+    }
+}
+
+impl Visitor for Linter<'_> {
+    fn visit_component(&mut self, component: &ComponentNode) {
+        self.component_has_factor = component.factor.is_some();
+
+        if let BasicComponentNode::Annotation(annotation) = &component.basic_component {
+            self.warnings.push(Warning {
+                kind: WarningKind::AnnotationAsUnit,
+                span: component.span,
+                message: format!(
+                    "the annotation `{{{}}}` is used as a unit, so it's treated as `1`",
+                    annotation.text
+                ),
+                suggestion: None,
+            });
+        }
+
+        walk_component(self, component);
+    }
+
+    fn visit_annotatable(&mut self, annotatable: &AnnotatableNode) {
+        self.check_annotatable(annotatable);
+        walk_annotatable(self, annotatable);
+    }
+}
+
+/// The exponent of `value`, if it's an exact power of ten.
+///
+#[allow(clippy::cast_possible_truncation)]
+fn power_of_ten(value: f64) -> Option<i32> {
+    let exponent = value.log10().round() as i32;
+
+    ((10_f64.powi(exponent) - value).abs() <= value.abs() * f64::EPSILON).then_some(exponent)
+}
+
+const fn deprecated_atom_replacement(atom: Atom) -> Option<&'static str> {
+    match atom {
+        Atom::PartsPerBillion => Some("10*-9"),
+        Atom::PartsPerTrillion => Some("10*-12"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(expression: &str) -> Vec<WarningKind> {
+        lint(expression)
+            .unwrap()
+            .into_iter()
+            .map(|warning| warning.kind)
+            .collect()
+    }
+
+    #[test]
+    fn validate_clean_expressions() {
+        for expression in [
+            "m",
+            "kg/(m.s2)",
+            "mg/(kg.d)",
+            "10*3/uL",
+            "Cel",
+            "[degF]",
+            "mL{total}",
+            "/s",
+            "[in_i]",
+        ] {
+            assert_eq!(lint(expression).unwrap(), [], "{expression}");
+        }
+    }
+
+    #[test]
+    fn validate_multiple_slashes() {
+        let warnings = lint("mg/kg/d").unwrap();
+        assert_eq!(
+            warnings,
+            [Warning {
+                kind: WarningKind::MultipleSlashes,
+                span: Span { start: 0, end: 7 },
+                message: "`mg/kg/d` has more than one `/`; UCUM reads it as `mg/(kg.d)`, but \
+                          wise_units currently parses it as `mg.d/kg`"
+                    .to_string(),
+                suggestion: Some("mg/(kg.d)".to_string()),
+            }]
+        );
+
+        let warnings = lint("/s/m").unwrap();
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("/(s.m)"));
+
+        let warnings = lint("2m.g/s/{tot}/(L.h)").unwrap();
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("2m.g/(s.{tot}.(L.h))")
+        );
+    }
+
+    #[test]
+    fn validate_prefixed_non_metric_atom() {
+        let warnings = lint("k[in_i]").unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::PrefixedNonMetricAtom);
+        assert_eq!(warnings[0].span, Span { start: 0, end: 7 });
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("10*3.[in_i]"));
+
+        let warnings = lint("m/k[in_i]2").unwrap();
+        assert_eq!(warnings[0].kind, WarningKind::PrefixedNonMetricAtom);
+        assert_eq!(warnings[0].suggestion, None);
+
+        let warnings = lint("2k[in_i]").unwrap();
+        assert_eq!(warnings[0].suggestion, None);
+    }
+
+    #[test]
+    fn validate_annotation_as_unit() {
+        let warnings = lint("{tablet}/d").unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::AnnotationAsUnit);
+        assert_eq!(warnings[0].span, Span { start: 0, end: 8 });
+    }
+
+    #[test]
+    fn validate_special_unit_in_compound() {
+        assert_eq!(kinds("Cel/s"), [WarningKind::SpecialUnitInCompound]);
+        assert_eq!(kinds("Cel2"), [WarningKind::SpecialUnitInCompound]);
+        assert_eq!(kinds("2[pH]"), [WarningKind::SpecialUnitInCompound]);
+        assert_eq!(kinds("Cel{body}"), []);
+    }
+
+    #[test]
+    fn validate_mixed_powers_of_ten() {
+        let warnings = lint("10*3.10^2/L").unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::MixedPowersOfTen);
+        assert_eq!(warnings[0].span, Span { start: 5, end: 8 });
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("10*3.10*2/L"));
+
+        let warnings = lint("10*3.10^2{10^x}.10^-1").unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("10*3.10*2{10^x}.10*-1")
+        );
+        assert_eq!(warnings[0].suggestion, warnings[1].suggestion);
+
+        assert_eq!(kinds("10^3/L"), []);
+    }
+
+    #[test]
+    fn validate_deprecated_atom() {
+        let warnings = lint("[ppb]").unwrap();
+        assert_eq!(warnings[0].kind, WarningKind::DeprecatedAtom);
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("10*-9"));
+
+        let warnings = lint("ug/L.[pptr]2").unwrap();
+        assert_eq!(warnings[0].kind, WarningKind::DeprecatedAtom);
+        assert_eq!(warnings[0].suggestion, None);
+    }
+
+    #[test]
+    fn validate_ordering_and_display() {
+        let warnings = lint("{cells}/k[in_i]/[ppb]").unwrap();
+        assert_eq!(
+            warnings.iter().map(|w| w.kind).collect::<Vec<_>>(),
+            [
+                WarningKind::MultipleSlashes,
+                WarningKind::AnnotationAsUnit,
+                WarningKind::PrefixedNonMetricAtom,
+                WarningKind::DeprecatedAtom,
+            ]
+        );
+        assert_eq!(
+            warnings[2].to_string(),
+            "8..15: `[in_i]` isn't metric, so it shouldn't take the prefix `k` (suggestion: \
+             `{cells}/10*3.[in_i]/[ppb]`)"
+        );
+    }
+
+    #[test]
+    fn validate_parse_errors() {
+        assert!(lint("m.foo").is_err());
+    }
+}
@@ -1,6 +1,8 @@
+mod approx_eq;
 mod composable;
 mod convertible;
 mod display;
+mod explain;
 mod field_eq;
 mod invert;
 mod is_compatible_with;
@@ -8,7 +10,10 @@ mod ops;
 mod partial_eq;
 mod partial_ord;
 mod reducible;
+mod round;
+mod to_base_units;
 mod to_reduced;
+mod trig;
 mod ucum_unit;
 
 use crate::{reducible::Reducible, ucum_unit::UcumUnit, unit::Unit};
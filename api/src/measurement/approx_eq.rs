@@ -0,0 +1,183 @@
+use super::Measurement;
+use crate::{
+    is_compatible_with::IsCompatibleWith, reducible::Reducible, ucum_unit::UcumUnit, Error,
+};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+//-----------------------------------------------------------------------------
+// impl AbsDiffEq, RelativeEq, UlpsEq
+//-----------------------------------------------------------------------------
+/// `Measurement`s are approximately equal if their `Unit`s are compatible and
+/// their `scalar()` values (in base units) are approximately equal. Same as
+/// `PartialEq`, but with an explicit tolerance.
+///
+/// ```rust
+/// use approx::{abs_diff_eq, relative_eq};
+/// use wise_units::Measurement;
+///
+/// let gram = Measurement::try_new(1.0, "g").unwrap();
+/// let milligrams = Measurement::try_new(1000.4, "mg").unwrap();
+///
+/// assert!(abs_diff_eq!(gram, milligrams, epsilon = 0.000_5));
+/// assert!(!relative_eq!(gram, milligrams));
+/// ```
+///
+impl AbsDiffEq for Measurement {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.is_compatible_with(other) && self.scalar().abs_diff_eq(&other.scalar(), epsilon)
+    }
+}
+
+impl RelativeEq for Measurement {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.is_compatible_with(other)
+            && self
+                .scalar()
+                .relative_eq(&other.scalar(), epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Measurement {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.is_compatible_with(other) && self.scalar().ulps_eq(&other.scalar(), epsilon, max_ulps)
+    }
+}
+
+impl Measurement {
+    /// Checks if `self` and `other` differ by no more than `tolerance` (ex.
+    /// "equal within 0.5 mg"). The `Measurement`s and the tolerance may all be
+    /// in different units, as long as they're compatible.
+    ///
+    /// For units like `Cel` or `[degF]`, `tolerance` is treated as a
+    /// temperature difference, so `0.5 Cel` is the same tolerance as `0.5 K`.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let expected = Measurement::try_new(10.0, "mg").unwrap();
+    /// let actual = Measurement::try_new(0.010_4, "g").unwrap();
+    /// let tolerance = Measurement::try_new(0.5, "mg").unwrap();
+    ///
+    /// assert!(actual.is_within(&expected, &tolerance).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `other` or `tolerance`
+    /// isn't compatible with `self`.
+    ///
+    pub fn is_within(&self, other: &Self, tolerance: &Self) -> Result<bool, Error> {
+        for rhs in [other, tolerance] {
+            if !self.is_compatible_with(rhs) {
+                return Err(Error::IncompatibleUnitTypes {
+                    lhs: self.unit.expression(),
+                    rhs: rhs.unit.expression(),
+                });
+            }
+        }
+
+        // The size of the tolerance, as a difference from 0 in base units. For
+        // non-special units this is the same as `tolerance.scalar()`.
+        let tolerance =
+            (tolerance.reduce_value(tolerance.value) - tolerance.reduce_value(0.0)).abs();
+
+        Ok((self.scalar() - other.scalar()).abs() <= tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_ne};
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_abs_diff_eq() {
+        assert_abs_diff_eq!(measurement(1.0, "km"), measurement(1000.0, "m"));
+        assert_abs_diff_eq!(measurement(1.0, "g"), measurement(1.1, "g"), epsilon = 0.2);
+        assert!(!measurement(1.0, "g").abs_diff_eq(&measurement(1.0, "m"), 10.0));
+    }
+
+    #[test]
+    fn validate_relative_eq() {
+        assert_relative_eq!(
+            measurement(1.0, "[in_i]"),
+            measurement(2.54, "cm"),
+            max_relative = 1e-12
+        );
+        assert!(relative_ne!(
+            measurement(1.0, "[in_i]"),
+            measurement(2.55, "cm")
+        ));
+        assert!(!measurement(1.0, "Hz").relative_eq(&measurement(1.0, "m"), 1.0, 1.0));
+    }
+
+    #[test]
+    fn validate_ulps_eq() {
+        assert_ulps_eq!(measurement(1.0, "L"), measurement(1000.0, "cm3"));
+        assert!(!measurement(1.0, "L").ulps_eq(&measurement(1.0, "g"), 1.0, 4));
+    }
+
+    #[test]
+    fn validate_is_within() {
+        let expected = measurement(10.0, "mg");
+        let tolerance = measurement(0.5, "mg");
+
+        assert!(measurement(10.4, "mg")
+            .is_within(&expected, &tolerance)
+            .unwrap());
+        assert!(measurement(0.009_6, "g")
+            .is_within(&expected, &tolerance)
+            .unwrap());
+        assert!(!measurement(10.6, "mg")
+            .is_within(&expected, &tolerance)
+            .unwrap());
+
+        assert!(measurement(10.0, "mg")
+            .is_within(&expected, &measurement(1.0, "m"))
+            .is_err());
+        assert!(measurement(10.0, "m")
+            .is_within(&expected, &tolerance)
+            .is_err());
+    }
+
+    #[test]
+    fn validate_is_within_special_units() {
+        let expected = measurement(20.0, "Cel");
+
+        assert!(measurement(20.4, "Cel")
+            .is_within(&expected, &measurement(0.5, "Cel"))
+            .unwrap());
+        assert!(measurement(293.55, "K")
+            .is_within(&expected, &measurement(0.5, "K"))
+            .unwrap());
+        assert!(!measurement(20.6, "Cel")
+            .is_within(&expected, &measurement(0.5, "K"))
+            .unwrap());
+        assert!(measurement(68.5, "[degF]")
+            .is_within(&expected, &measurement(1.0, "[degF]"))
+            .unwrap());
+    }
+}
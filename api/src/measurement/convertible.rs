@@ -1,4 +1,7 @@
-use crate::{Convertible, Error, FieldEq, IsCompatibleWith, Measurement, Unit};
+use crate::{
+    annotation_rules::expand_annotations, Convertible, Error, FieldEq, IsCompatibleWith,
+    Measurement, Unit,
+};
 use std::str::FromStr;
 
 /// This implementation of `Convertible` lets you pass in a `&str` for the
@@ -42,6 +45,10 @@ fn convert_measurement(lhs: &Measurement, dest_unit: &Unit) -> Result<Measuremen
     let source_unit = &lhs.unit;
 
     if !source_unit.is_compatible_with(dest_unit) {
+        if let Some(measurement) = convert_with_annotation_rules(lhs, dest_unit) {
+            return measurement;
+        }
+
         let e = Error::IncompatibleUnitTypes {
             lhs: source_unit.expression(),
             rhs: dest_unit.expression(),
@@ -57,6 +64,38 @@ fn convert_measurement(lhs: &Measurement, dest_unit: &Unit) -> Result<Measuremen
     Ok(new_measurement)
 }
 
+/// Converts by replacing annotations in both units, using the current
+/// `AnnotationRules`. `None` means the rules didn't make the units compatible.
+///
+fn convert_with_annotation_rules(
+    lhs: &Measurement,
+    dest_unit: &Unit,
+) -> Option<Result<Measurement, Error>> {
+    let source = expand_annotations(&lhs.unit);
+    let dest = expand_annotations(dest_unit);
+
+    if source.is_none() && dest.is_none() {
+        return None;
+    }
+
+    let (source_scale, source_unit) = source.unwrap_or_else(|| (1.0, lhs.unit.clone()));
+    let (dest_scale, expanded_dest_unit) = dest.unwrap_or_else(|| (1.0, dest_unit.clone()));
+
+    if !source_unit.is_compatible_with(&expanded_dest_unit) {
+        return None;
+    }
+
+    let expanded = Measurement {
+        value: lhs.value * source_scale,
+        unit: source_unit,
+    };
+
+    Some(Ok(Measurement {
+        value: expanded.converted_scalar(&expanded_dest_unit) / dest_scale,
+        unit: dest_unit.clone(),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
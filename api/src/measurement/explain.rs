@@ -0,0 +1,174 @@
+use super::Measurement;
+use crate::{
+    explain::{ConversionMethod, ConversionTrace, SpecialDirection, SpecialStep, UnitTrace},
+    reducible::Reducible,
+    Error, IsCompatibleWith, UcumUnit, Unit,
+};
+
+impl Measurement {
+    /// Explains how `self` would get converted to `other_unit`: how each unit
+    /// reduces to base units (including each atom's definition and each
+    /// prefix's multiplier), plus either the linear factor or the special
+    /// function steps that get applied to the value. The `Display`
+    /// implementation of the returned `ConversionTrace` renders it as text.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let measurement = Measurement::try_new(1.0, "[in_i]").unwrap();
+    /// let unit = Unit::from_str("cm").unwrap();
+    /// let trace = measurement.explain_conversion(&unit).unwrap();
+    ///
+    /// assert!((trace.result - 2.54).abs() < 1e-9);
+    /// println!("{}", trace);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self`'s unit can't be
+    /// converted to `other_unit`.
+    ///
+    pub fn explain_conversion(&self, other_unit: &Unit) -> Result<ConversionTrace, Error> {
+        if !self.unit.is_compatible_with(other_unit) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: other_unit.expression(),
+            });
+        }
+
+        let method = if self.is_special() || other_unit.is_special() {
+            let mut steps = Vec::new();
+            let mut value = self.value;
+
+            if self.is_special() {
+                let output = self.unit.reduce_value(value);
+                steps.push(SpecialStep {
+                    unit: self.unit.expression(),
+                    direction: SpecialDirection::ToBase,
+                    input: value,
+                    output,
+                });
+                value = output;
+            }
+
+            if other_unit.is_special() {
+                let output = other_unit.calculate_magnitude(value);
+                steps.push(SpecialStep {
+                    unit: other_unit.expression(),
+                    direction: SpecialDirection::FromBase,
+                    input: value,
+                    output,
+                });
+            }
+
+            ConversionMethod::Special { steps }
+        } else {
+            ConversionMethod::Linear {
+                factor: self.unit.scalar() / other_unit.reduce_value(1.0),
+            }
+        };
+
+        Ok(ConversionTrace {
+            value: self.value,
+            source: UnitTrace::new(&self.unit),
+            destination: UnitTrace::new(other_unit),
+            method,
+            result: self.converted_scalar(other_unit),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        explain::{ConversionMethod, SpecialDirection},
+        Atom, Measurement, Prefix, Unit,
+    };
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_explain_linear() {
+        let measurement = Measurement::try_new(2.0, "[in_i]").unwrap();
+        let unit = Unit::from_str("cm").unwrap();
+        let trace = measurement.explain_conversion(&unit).unwrap();
+
+        assert_relative_eq!(trace.result, 5.08);
+
+        match trace.method {
+            ConversionMethod::Linear { factor } => assert_relative_eq!(factor, 2.54),
+            ConversionMethod::Special { .. } => panic!("expected a linear conversion"),
+        }
+
+        let inch = trace.source.terms[0].atom.as_ref().unwrap();
+        assert_eq!(inch.atom, Atom::InchInternational);
+        assert_relative_eq!(inch.definition_value, 2.54);
+        assert_eq!(inch.definition_expression, "cm");
+
+        let centimeter = &inch.definition[0];
+        assert_eq!(centimeter.prefix.unwrap().prefix, Prefix::Centi);
+        assert_relative_eq!(centimeter.prefix.unwrap().value, 0.01);
+        assert!(centimeter.atom.as_ref().unwrap().definition.is_empty());
+    }
+
+    #[test]
+    fn validate_explain_exponents() {
+        let measurement = Measurement::try_new(1.0, "m2").unwrap();
+        let unit = Unit::from_str("cm2").unwrap();
+        let trace = measurement.explain_conversion(&unit).unwrap();
+
+        assert_eq!(trace.destination.terms[0].exponent, 2);
+        assert_relative_eq!(trace.destination.terms[0].scalar, 0.0001);
+        assert_relative_eq!(trace.result, 10_000.0);
+    }
+
+    #[test]
+    fn validate_explain_special() {
+        let measurement = Measurement::try_new(25.0, "Cel").unwrap();
+        let unit = Unit::from_str("[degF]").unwrap();
+        let trace = measurement.explain_conversion(&unit).unwrap();
+
+        match &trace.method {
+            ConversionMethod::Special { steps } => {
+                assert_eq!(steps.len(), 2);
+                assert_eq!(steps[0].direction, SpecialDirection::ToBase);
+                assert_relative_eq!(steps[0].output, 298.15);
+                assert_eq!(steps[1].direction, SpecialDirection::FromBase);
+                assert_relative_eq!(steps[1].output, trace.result);
+            }
+            ConversionMethod::Linear { .. } => panic!("expected a special conversion"),
+        }
+
+        assert_relative_eq!(trace.result, 77.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn validate_explain_incompatible() {
+        let measurement = Measurement::try_new(1.0, "m").unwrap();
+        let unit = Unit::from_str("g").unwrap();
+
+        assert!(measurement.explain_conversion(&unit).is_err());
+    }
+
+    #[test]
+    fn validate_display() {
+        let measurement = Measurement::try_new(1.0, "km").unwrap();
+        let unit = Unit::from_str("m").unwrap();
+        let text = measurement.explain_conversion(&unit).unwrap().to_string();
+
+        assert_eq!(
+            text,
+            "Converting 1 km to m\n\
+             Source: km (scalar 1000)\n  \
+               km: prefix k (1000) * m (1) = 1000\n    \
+                 m is a base unit\n\
+             Destination: m (scalar 1)\n  \
+               m: m (1) = 1\n    \
+                 m is a base unit\n\
+             Factor: 1000 / 1 = 1000\n\
+             Result: 1 * 1000 = 1000\n\
+             = 1000 m"
+        );
+    }
+}
@@ -1,6 +1,44 @@
 use crate::{convertible::Convertible, error::Error, measurement::Measurement};
 use std::ops::{Add, Div, Mul, Sub};
 
+impl Measurement {
+    /// Same as `self * other`, but returns an `Error::Overflow` instead of
+    /// panicking if combining the units' exponents overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Overflow` if any resulting exponent doesn't fit in an
+    /// `i32`.
+    ///
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        let converted_other = other.convert_to(&self.unit);
+        let actual_other = converted_other.as_ref().unwrap_or(other);
+
+        Ok(Self {
+            value: self.value * actual_other.value,
+            unit: self.unit.checked_mul(&actual_other.unit)?,
+        })
+    }
+
+    /// Same as `self / other`, but returns an `Error::Overflow` instead of
+    /// panicking if combining the units' exponents overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Overflow` if any resulting exponent doesn't fit in an
+    /// `i32`.
+    ///
+    pub fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        let converted_other = other.convert_to(&self.unit);
+        let actual_other = converted_other.as_ref().unwrap_or(other);
+
+        Ok(Self {
+            value: self.value / actual_other.value,
+            unit: self.unit.checked_div(&actual_other.unit)?,
+        })
+    }
+}
+
 //-----------------------------------------------------------------------------
 // impl Add
 //-----------------------------------------------------------------------------
@@ -115,15 +153,8 @@ impl<'a> Sub<Measurement> for &'a Measurement {
     ffi_common::derive::expose_fn(extend_type(Measurement))
 )]
 fn mul_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
-    let converted_rhs = rhs.convert_to(&lhs.unit);
-    let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
-    let new_value = lhs.value * actual_rhs.value;
-    let new_unit = &lhs.unit * &actual_rhs.unit;
-
-    Measurement {
-        value: new_value,
-        unit: new_unit,
-    }
+    lhs.checked_mul(rhs)
+        .unwrap_or_else(|error| panic!("Unable to multiply measurements: {}", error))
 }
 
 impl Mul for Measurement {
@@ -202,15 +233,8 @@ impl<'a> Mul<f64> for &'a Measurement {
     ffi_common::derive::expose_fn(extend_type(Measurement))
 )]
 fn div_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
-    let converted_rhs = rhs.convert_to(&lhs.unit);
-    let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
-    let new_value = lhs.value / actual_rhs.value;
-    let new_unit = &lhs.unit / &actual_rhs.unit;
-
-    Measurement {
-        value: new_value,
-        unit: new_unit,
-    }
+    lhs.checked_div(rhs)
+        .unwrap_or_else(|error| panic!("Unable to divide measurements: {}", error))
 }
 
 impl Div for Measurement {
@@ -583,4 +607,27 @@ mod tests {
             assert_eq!(m.div(5.0), expected);
         }
     }
+
+    mod checked {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn validate_checked_mul_and_div() {
+            let m1 = Measurement::try_new(2.0, "m").unwrap();
+            let m2 = Measurement::try_new(3.0, "m").unwrap();
+            let expected = Measurement::try_new(6.0, "m2").unwrap();
+            assert_eq!(m1.checked_mul(&m2).unwrap(), expected);
+
+            let expected = Measurement::try_new(2.0 / 3.0, "1").unwrap();
+            assert_eq!(m1.checked_div(&m2).unwrap(), expected);
+
+            let big = Measurement::try_new(1.0, "s-2147483647").unwrap();
+            let overflow = Error::Overflow {
+                term: "s-2".to_string(),
+            };
+            let other = Measurement::try_new(1.0, "s-2").unwrap();
+            assert_eq!(big.checked_mul(&other).unwrap_err(), overflow);
+        }
+    }
 }
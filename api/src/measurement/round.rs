@@ -0,0 +1,168 @@
+use super::Measurement;
+use crate::{
+    is_compatible_with::IsCompatibleWith,
+    precision::{round_to_decimal_places, round_to_significant_figures},
+    reducible::Reducible,
+    ucum_unit::UcumUnit,
+    Convertible, Error, Unit,
+};
+
+impl Measurement {
+    /// Rounds the value to `significant_figures` significant figures.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let measurement = Measurement::try_new(1234.5, "g").unwrap();
+    /// assert_eq!(measurement.round_to_sig_figs(2).value(), 1200.0);
+    /// ```
+    ///
+    #[must_use]
+    pub fn round_to_sig_figs(&self, significant_figures: u32) -> Self {
+        Self::new(
+            round_to_significant_figures(self.value, significant_figures),
+            self.unit.clone(),
+        )
+    }
+
+    /// Rounds the value to the nearest multiple of `increment` (ex. to the
+    /// nearest `0.5 mg`), where `increment` may be in any compatible unit.
+    ///
+    /// For units like `Cel` or `[degF]`, `increment` is treated as a
+    /// temperature difference, so `0.5 K` is the same increment as `0.5 Cel`.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let measurement = Measurement::try_new(1234.0, "g").unwrap();
+    /// let increment = Measurement::try_new(0.05, "kg").unwrap();
+    ///
+    /// assert_eq!(measurement.round_to_increment(&increment).unwrap().value(), 1250.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IncompatibleUnitTypes` if `increment` isn't compatible with
+    ///   `self`.
+    /// * `Error::DivideByZero` if `increment` is 0.
+    ///
+    pub fn round_to_increment(&self, increment: &Self) -> Result<Self, Error> {
+        let increment = increment.difference_in_unit(&self.unit)?.abs();
+
+        if increment == 0.0 {
+            return Err(Error::DivideByZero);
+        }
+
+        let value = (self.value / increment).round() * increment;
+
+        // Clean up any floating point noise from the multiplication (ex.
+        // 0.30000000000000004).
+        let value = round_to_decimal_places(value, 12 - magnitude_or_zero(value));
+
+        Ok(Self::new(value, self.unit.clone()))
+    }
+
+    /// The value of `self`, treated as a difference (ex. between two
+    /// temperatures), in terms of `unit`. For non-special units, this is the
+    /// same as converting `self` to `unit`.
+    ///
+    pub(crate) fn difference_in_unit(&self, unit: &Unit) -> Result<f64, Error> {
+        if !self.unit.is_compatible_with(unit) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: unit.expression(),
+            });
+        }
+
+        if !self.is_special() && !unit.is_special() {
+            return self.convert_to(unit).map(|converted| converted.value);
+        }
+
+        let base_difference = self.reduce_value(self.value) - self.reduce_value(0.0);
+        let unit_size =
+            Self::new(1.0, unit.clone()).scalar() - Self::new(0.0, unit.clone()).scalar();
+
+        Ok(base_difference / unit_size)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn magnitude_or_zero(value: f64) -> i32 {
+    if value == 0.0 {
+        0
+    } else {
+        value.abs().log10().floor() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_round_to_sig_figs() {
+        assert_relative_eq!(
+            measurement(1.234_56, "g").round_to_sig_figs(3).value(),
+            1.23
+        );
+        assert_relative_eq!(
+            measurement(0.001_256, "g").round_to_sig_figs(2).value(),
+            0.001_3
+        );
+        assert_relative_eq!(
+            measurement(98_765.0, "g").round_to_sig_figs(1).value(),
+            100_000.0
+        );
+        assert_relative_eq!(measurement(0.0, "g").round_to_sig_figs(3).value(), 0.0);
+    }
+
+    // The first check is exact, since floating point noise should be cleaned up.
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn validate_round_to_increment() {
+        let increment = measurement(0.1, "g");
+        assert_eq!(
+            measurement(0.26, "g")
+                .round_to_increment(&increment)
+                .unwrap()
+                .value(),
+            0.3
+        );
+
+        let increment = measurement(500.0, "ug");
+        assert_relative_eq!(
+            measurement(10.37, "mg")
+                .round_to_increment(&increment)
+                .unwrap()
+                .value(),
+            10.5
+        );
+
+        let increment = measurement(0.5, "K");
+        assert_relative_eq!(
+            measurement(20.3, "Cel")
+                .round_to_increment(&increment)
+                .unwrap()
+                .value(),
+            20.5
+        );
+
+        let increment = measurement(1.0, "m");
+        assert!(measurement(1.0, "g")
+            .round_to_increment(&increment)
+            .is_err());
+
+        let increment = measurement(0.0, "g");
+        assert_eq!(
+            measurement(1.0, "g")
+                .round_to_increment(&increment)
+                .unwrap_err(),
+            Error::DivideByZero
+        );
+    }
+}
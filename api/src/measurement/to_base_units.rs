@@ -0,0 +1,148 @@
+use super::Measurement;
+use crate::{
+    parser::Composable, ucum_unit::UcumUnit, unit::si_unit_from_composition, Convertible, Error,
+    Unit,
+};
+
+impl Measurement {
+    /// Converts `self` to the UCUM base units for its dimension (`m`, `g`,
+    /// `s`, `rad`, `K`, `C` and `cd`), without having to name the target
+    /// `Unit`. Useful for storing heterogeneous measurements in a normalized
+    /// form.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let measurement = Measurement::try_new(1.0, "[psi]").unwrap();
+    /// let base = measurement.to_base_units().unwrap();
+    ///
+    /// assert_eq!(base.unit().expression(), "g/m.s2");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self`'s `Unit` is
+    /// arbitrary (ex. `[IU]`) or annotated (ex. `mg{creat}`), since those
+    /// can't be expressed in base units.
+    ///
+    pub fn to_base_units(&self) -> Result<Self, Error> {
+        self.convert_to_normalized(&Unit::from_composition(self.composition()))
+    }
+
+    /// Converts `self` to SI base units: the same as `to_base_units()`, but
+    /// with `kg` instead of `g`.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let measurement = Measurement::try_new(1.0, "[lbf_av]").unwrap();
+    /// let si = measurement.to_si().unwrap();
+    ///
+    /// assert_eq!(si.unit().expression(), "m.kg/s2");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `to_base_units()`.
+    ///
+    pub fn to_si(&self) -> Result<Self, Error> {
+        self.convert_to_normalized(&si_unit_from_composition(self.composition(), false))
+    }
+
+    /// Converts `self` to SI units, using a named SI derived unit (ex. `N`,
+    /// `Pa`, `J`, `W`, `V`) if one exists for `self`'s dimension, otherwise
+    /// the same as `to_si()`. Dimensions that are shared by more than one kind
+    /// of quantity (ex. `Hz` and `Bq`) aren't given a named unit.
+    ///
+    /// ```rust
+    /// use wise_units::Measurement;
+    ///
+    /// let measurement = Measurement::try_new(1.0, "[lbf_av]").unwrap();
+    /// let si = measurement.to_si_with_derived_units().unwrap();
+    ///
+    /// assert_eq!(si.unit().expression(), "N");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `to_base_units()`.
+    ///
+    pub fn to_si_with_derived_units(&self) -> Result<Self, Error> {
+        self.convert_to_normalized(&si_unit_from_composition(self.composition(), true))
+    }
+
+    fn convert_to_normalized(&self, unit: &Unit) -> Result<Self, Error> {
+        if self.is_arbitrary() {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: unit.expression(),
+            });
+        }
+
+        self.convert_to(unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_to_base_units() {
+        let base = measurement(2.0, "km/h").to_base_units().unwrap();
+        assert_eq!(base.unit().expression(), "m/s");
+        assert_relative_eq!(base.value(), 2000.0 / 3600.0);
+
+        let base = measurement(1.0, "L").to_base_units().unwrap();
+        assert_eq!(base.unit().expression(), "m3");
+        assert_relative_eq!(base.value(), 0.001);
+
+        let base = measurement(20.0, "Cel").to_base_units().unwrap();
+        assert_eq!(base.unit().expression(), "K");
+        assert_relative_eq!(base.value(), 293.15);
+
+        let base = measurement(5.0, "%").to_base_units().unwrap();
+        assert_eq!(base.unit().expression(), "1");
+        assert_relative_eq!(base.value(), 0.05);
+
+        assert!(measurement(1.0, "[IU]").to_base_units().is_err());
+        assert!(measurement(1.0, "mg{creat}").to_base_units().is_err());
+    }
+
+    #[test]
+    fn validate_to_si() {
+        let si = measurement(500.0, "g").to_si().unwrap();
+        assert_eq!(si.unit().expression(), "kg");
+        assert_relative_eq!(si.value(), 0.5);
+
+        let si = measurement(1.0, "kJ").to_si().unwrap();
+        assert_eq!(si.unit().expression(), "m2.kg/s2");
+        assert_relative_eq!(si.value(), 1000.0);
+    }
+
+    #[test]
+    fn validate_to_si_with_derived_units() {
+        let si = measurement(1.0, "kJ").to_si_with_derived_units().unwrap();
+        assert_eq!(si.unit().expression(), "J");
+        assert_relative_eq!(si.value(), 1000.0);
+
+        let si = measurement(760.0, "mm[Hg]")
+            .to_si_with_derived_units()
+            .unwrap();
+        assert_eq!(si.unit().expression(), "Pa");
+        assert_relative_eq!(si.value(), 101_325.0, max_relative = 1e-5);
+
+        let si = measurement(1.0, "kHz").to_si_with_derived_units().unwrap();
+        assert_eq!(si.unit().expression(), "/s");
+        assert_relative_eq!(si.value(), 1000.0);
+
+        let si = measurement(1.0, "g/L").to_si_with_derived_units().unwrap();
+        assert_eq!(si.unit().expression(), "kg/m3");
+        assert_relative_eq!(si.value(), 1.0);
+    }
+}
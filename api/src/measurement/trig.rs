@@ -0,0 +1,262 @@
+use super::Measurement;
+use crate::{
+    parser::{Atom, Composable, Composition, Dimension, Term},
+    Convertible, Error, Unit,
+};
+
+fn radians() -> Unit {
+    Unit::new(vec![Term::new(None, Some(Atom::Radian))])
+}
+
+impl Measurement {
+    /// The sine of `self`, which must be a plane angle (ex. `rad`, `deg`,
+    /// `gon`, `'`).
+    ///
+    /// ```rust
+    /// use approx::assert_relative_eq;
+    /// use wise_units::Measurement;
+    ///
+    /// let angle = Measurement::try_new(30.0, "deg").unwrap();
+    /// assert_relative_eq!(angle.sin().unwrap(), 0.5, epsilon = 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self` isn't a plane angle.
+    ///
+    pub fn sin(&self) -> Result<f64, Error> {
+        self.to_radians().map(f64::sin)
+    }
+
+    /// The cosine of `self`, which must be a plane angle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self` isn't a plane angle.
+    ///
+    pub fn cos(&self) -> Result<f64, Error> {
+        self.to_radians().map(f64::cos)
+    }
+
+    /// The tangent of `self`, which must be a plane angle (ex. the slope for a
+    /// grade angle).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self` isn't a plane angle.
+    ///
+    pub fn tan(&self) -> Result<f64, Error> {
+        self.to_radians().map(f64::tan)
+    }
+
+    /// The arcsine of `value`, as an angle in `rad`. Like `f64::asin()`, the
+    /// value is `NaN` if `value` is outside of [-1, 1].
+    ///
+    #[must_use]
+    pub fn from_asin(value: f64) -> Self {
+        Self::new(value.asin(), radians())
+    }
+
+    /// The arccosine of `value`, as an angle in `rad`. Like `f64::acos()`, the
+    /// value is `NaN` if `value` is outside of [-1, 1].
+    ///
+    #[must_use]
+    pub fn from_acos(value: f64) -> Self {
+        Self::new(value.acos(), radians())
+    }
+
+    /// The arctangent of `value` (ex. a slope), as an angle in `rad`.
+    ///
+    #[must_use]
+    pub fn from_atan(value: f64) -> Self {
+        Self::new(value.atan(), radians())
+    }
+
+    /// The four-quadrant arctangent of `y` and `x`, as an angle in `rad`. `y`
+    /// and `x` may be in different, compatible units (ex. a rise in `[ft_i]`
+    /// and a run in `m`).
+    ///
+    /// ```rust
+    /// use approx::assert_relative_eq;
+    /// use std::f64::consts::FRAC_PI_4;
+    /// use wise_units::Measurement;
+    ///
+    /// let rise = Measurement::try_new(100.0, "cm").unwrap();
+    /// let run = Measurement::try_new(1.0, "m").unwrap();
+    /// let angle = Measurement::atan2(&rise, &run).unwrap();
+    ///
+    /// assert_eq!(angle.unit().expression(), "rad");
+    /// assert_relative_eq!(angle.value(), FRAC_PI_4);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `y` and `x` aren't
+    /// compatible.
+    ///
+    pub fn atan2(y: &Self, x: &Self) -> Result<Self, Error> {
+        let x = x.convert_to(&y.unit)?;
+
+        Ok(Self::new(y.value.atan2(x.value), radians()))
+    }
+
+    /// Converts any plane (or solid) angle terms in `self` to `rad` and then
+    /// removes them, treating `rad` as dimensionless like SI does. This is
+    /// useful for plugging angles into formulas that expect a pure number (ex.
+    /// an angular velocity in `deg/s` times a radius in `m` gives a tangential
+    /// velocity in `m/s`).
+    ///
+    /// ```rust
+    /// use approx::assert_relative_eq;
+    /// use wise_units::Measurement;
+    ///
+    /// let angular_velocity = Measurement::try_new(180.0, "deg/s").unwrap();
+    /// let stripped = angular_velocity.strip_angles().unwrap();
+    ///
+    /// assert_eq!(stripped.unit().expression(), "/s");
+    /// assert_relative_eq!(stripped.value(), std::f64::consts::PI);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `self` couldn't be converted to radians.
+    ///
+    pub fn strip_angles(&self) -> Result<Self, Error> {
+        let mut angle_exponent = 0;
+        let mut terms = Vec::with_capacity(self.unit.terms().len());
+
+        for term in self.unit.terms() {
+            match angle_only_exponent(term) {
+                Some(exponent) => angle_exponent += exponent,
+                None => terms.push(term.clone()),
+            }
+        }
+
+        if terms.len() == self.unit.terms().len() {
+            return Ok(self.clone());
+        }
+
+        let unit_without_angles = if terms.is_empty() {
+            Unit::new_unity()
+        } else {
+            Unit::new(terms.clone())
+        };
+
+        if angle_exponent != 0 {
+            let mut radians = Term::new(None, Some(Atom::Radian));
+            radians.exponent = Some(angle_exponent);
+            terms.push(radians);
+        }
+
+        let converted = self.convert_to(&Unit::new(terms))?;
+
+        Ok(Self::new(converted.value, unit_without_angles))
+    }
+
+    fn to_radians(&self) -> Result<f64, Error> {
+        self.convert_to(&radians()).map(|radians| radians.value)
+    }
+}
+
+/// If `term` is only made up of plane angles (ex. `deg`, `mrad`, `sr`), the
+/// plane angle exponent.
+///
+#[allow(clippy::question_mark)]
+fn angle_only_exponent(term: &Term) -> Option<i32> {
+    if term.atom.is_none() {
+        return None;
+    }
+
+    let composition = term.composition();
+    let exponent = composition.exponent(Dimension::PlaneAngle)?;
+
+    (composition == Composition::new_plane_angle(exponent)).then_some(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_trig_functions() {
+        assert_relative_eq!(measurement(FRAC_PI_2, "rad").sin().unwrap(), 1.0);
+        assert_relative_eq!(measurement(90.0, "deg").sin().unwrap(), 1.0);
+        assert_relative_eq!(
+            measurement(60.0, "deg").cos().unwrap(),
+            0.5,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            measurement(50.0, "gon").tan().unwrap(),
+            1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            measurement(2700.0, "'").tan().unwrap(),
+            1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(measurement(0.5, "circ").cos().unwrap(), -1.0);
+        assert_relative_eq!(measurement(1000.0, "mrad").sin().unwrap(), 1.0_f64.sin());
+    }
+
+    #[test]
+    fn validate_trig_functions_require_angles() {
+        assert_eq!(
+            measurement(1.0, "m").sin(),
+            Err(Error::IncompatibleUnitTypes {
+                lhs: "m".to_string(),
+                rhs: "rad".to_string()
+            })
+        );
+        assert!(measurement(1.0, "1").cos().is_err());
+        assert!(measurement(1.0, "sr").tan().is_err());
+    }
+
+    #[test]
+    fn validate_inverse_trig_functions() {
+        let angle = Measurement::from_asin(1.0);
+        assert_eq!(angle.unit().expression(), "rad");
+        assert_relative_eq!(angle.value(), FRAC_PI_2);
+
+        assert_relative_eq!(Measurement::from_acos(-1.0).value(), PI);
+        assert_relative_eq!(Measurement::from_atan(1.0).value(), FRAC_PI_4);
+        assert!(Measurement::from_asin(2.0).value().is_nan());
+
+        let rise = measurement(-1.0, "[ft_i]");
+        let run = measurement(-0.3048, "m");
+        let angle = Measurement::atan2(&rise, &run).unwrap();
+        assert_relative_eq!(angle.value(), -3.0 * FRAC_PI_4, epsilon = 1e-12);
+        assert_relative_eq!(angle.convert_to("deg").unwrap().value(), -135.0);
+
+        assert!(Measurement::atan2(&rise, &measurement(1.0, "g")).is_err());
+    }
+
+    #[test]
+    fn validate_strip_angles() {
+        let stripped = measurement(2.0, "rad.m").strip_angles().unwrap();
+        assert_eq!(stripped, measurement(2.0, "m"));
+        assert_eq!(stripped.unit().expression(), "m");
+
+        let stripped = measurement(90.0, "deg").strip_angles().unwrap();
+        assert_eq!(stripped.unit().expression(), "1");
+        assert_relative_eq!(stripped.value(), FRAC_PI_2);
+
+        let stripped = measurement(1.0, "cd.sr").strip_angles().unwrap();
+        assert_eq!(stripped.unit().expression(), "cd");
+        assert_relative_eq!(stripped.value(), 1.0);
+
+        let stripped = measurement(60.0, "deg/min").strip_angles().unwrap();
+        assert_eq!(stripped.unit().expression(), "/min");
+        assert_relative_eq!(stripped.value(), PI / 3.0);
+
+        let unchanged = measurement(1.0, "m/s");
+        assert_eq!(unchanged.strip_angles().unwrap(), unchanged);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod ast;
+
 // Because long numbers are generated, there's no way (that I know of) to
 // generate them using underscores (to make them pass the clippy lint).
 #[cfg_attr(
@@ -36,6 +38,8 @@ pub use self::{
     prefix::Prefix, property::Property, term::Term, ucum_symbol::UcumSymbol,
 };
 
+pub(crate) use self::term::validate_composition;
+
 use self::{
     symbols::symbol_parser::Rule as SymbolRule,
     terms::term_parser::{Rule as TermRule, TermParser},
@@ -45,7 +49,12 @@ use pest::{iterators::Pair, Parser};
 #[inline]
 pub(crate) fn parse(expression: &str) -> Result<Vec<Term>, Error> {
     match TermParser::parse(TermRule::main_term, expression) {
-        Ok(pairs) => Ok(terms::mapper::map(pairs)?),
+        Ok(pairs) => {
+            let terms = terms::mapper::map(pairs)?;
+            validate_composition(&terms)?;
+
+            Ok(terms)
+        }
         Err(_) => Err(Error::UnknownUnitString(expression.to_string())),
     }
 }
@@ -0,0 +1,722 @@
+//! A syntax tree for unit expressions.
+//!
+//! `parser::parse()` maps the grammar straight to a flat list of `Term`s,
+//! which loses things like parenthesized groups, which slash a term came from,
+//! and how each symbol was spelled (ex. `CEL` vs `Cel`). This module keeps all
+//! of that, along with the byte `Span` of every node in the original
+//! expression, so that linters, syntax highlighters and editors can be built
+//! on top of the real grammar. `Expression::to_unit()` lowers the tree to a
+//! `Unit`.
+//!
+//! ```rust
+//! use wise_units::parser::ast::{self, SimpleUnitNode, Visitor};
+//!
+//! struct Symbols(Vec<String>);
+//!
+//! impl Visitor for Symbols {
+//!     fn visit_simple_unit(&mut self, simple_unit: &SimpleUnitNode) {
+//!         self.0.push(simple_unit.text.clone());
+//!     }
+//! }
+//!
+//! let expression = ast::parse("kg/(CEL.s2)").unwrap();
+//! let mut symbols = Symbols(Vec::new());
+//! symbols.visit_expression(&expression);
+//!
+//! assert_eq!(symbols.0, ["kg", "CEL", "s"]);
+//! assert_eq!(expression.to_unit().unwrap().expression(), "kg/Cel.s2");
+//! ```
+//!
+use super::{
+    terms::{
+        mapper::{Exponent, Factor, SimpleUnit},
+        term_parser::{Rule, TermParser},
+    },
+    validate_composition, Atom, Error, Prefix, Term, Visit,
+};
+use crate::{invert::Invert, Unit};
+use pest::{iterators::Pair, Parser};
+use std::str::FromStr;
+
+/// The byte range of a node in the original expression.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// The part of `expression` that `self` covers.
+    ///
+    #[must_use]
+    pub fn slice<'a>(&self, expression: &'a str) -> &'a str {
+        &expression[self.start..self.end]
+    }
+}
+
+impl From<pest::Span<'_>> for Span {
+    fn from(span: pest::Span<'_>) -> Self {
+        Self {
+            start: span.start(),
+            end: span.end(),
+        }
+    }
+}
+
+/// A whole unit expression (ex. `/kg.m`).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    /// The original expression.
+    pub source: String,
+    pub span: Span,
+
+    /// The span of the leading slash, if the expression starts with one (ex.
+    /// `/s`).
+    pub leading_slash: Option<Span>,
+    pub term: TermNode,
+}
+
+/// A component, optionally followed by an operator and the rest of the term
+/// (ex. `kg.m/s2`).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermNode {
+    pub span: Span,
+    pub component: ComponentNode,
+    pub rest: Option<(Operator, Box<Self>)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Operator {
+    pub kind: OperatorKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperatorKind {
+    /// `.`: multiplication.
+    Dot,
+
+    /// `/`: division; everything to the right of it is inverted.
+    Slash,
+}
+
+/// A basic component, optionally preceded by a factor (ex. `2km`).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentNode {
+    pub span: Span,
+    pub factor: Option<FactorNode>,
+    pub basic_component: BasicComponentNode,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BasicComponentNode {
+    /// A unit symbol with an optional exponent and annotation (ex.
+    /// `km2{land}`).
+    Annotatable {
+        span: Span,
+        annotatable: AnnotatableNode,
+        annotation: Option<AnnotationNode>,
+    },
+
+    /// An annotation on its own (ex. `{tablet}`).
+    Annotation(AnnotationNode),
+
+    /// A number on its own (ex. the `100` in `/100`).
+    Factor(FactorNode),
+
+    /// A parenthesized term (ex. `(m.s)`). The span includes the parentheses.
+    Group { span: Span, term: Box<TermNode> },
+}
+
+impl BasicComponentNode {
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Annotatable { span, .. } | Self::Group { span, .. } => *span,
+            Self::Annotation(annotation) => annotation.span,
+            Self::Factor(factor) => factor.span,
+        }
+    }
+}
+
+/// A unit symbol with an optional exponent (ex. `km2`).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotatableNode {
+    pub span: Span,
+    pub simple_unit: SimpleUnitNode,
+    pub exponent: Option<ExponentNode>,
+}
+
+/// A unit symbol (ex. `km`), as it was spelled in the expression, along with
+/// the `Prefix` and `Atom` it resolved to. `atom` is `None` for `1`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimpleUnitNode {
+    pub span: Span,
+    pub text: String,
+    pub prefix: Option<Prefix>,
+    pub atom: Option<Atom>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExponentNode {
+    pub span: Span,
+    pub value: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FactorNode {
+    pub span: Span,
+    pub value: u64,
+}
+
+/// The text between curly braces (ex. `tablet` in `{tablet}`). The span
+/// doesn't include the braces.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AnnotationNode {
+    pub span: Span,
+    pub text: String,
+}
+
+/// Parses `expression` into an `Expression`.
+///
+/// # Errors
+///
+/// Returns the same errors as parsing a `Unit` (ex. `Error::BadFragment` for
+/// an unknown symbol, with its position).
+///
+pub fn parse(expression: &str) -> Result<Expression, Error> {
+    let main_term = TermParser::parse(Rule::main_term, expression)
+        .map_err(|_| Error::UnknownUnitString(expression.to_string()))?
+        .next()
+        .ok_or_else(|| Error::UnknownUnitString(expression.to_string()))?;
+
+    let span = Span::from(main_term.as_span());
+    let mut leading_slash = None;
+    let mut term = None;
+
+    for pair in main_term.into_inner() {
+        match pair.as_rule() {
+            Rule::slash => leading_slash = Some(Span::from(pair.as_span())),
+            Rule::term => term = Some(build_term(pair)?),
+            _ => (),
+        }
+    }
+
+    Ok(Expression {
+        source: expression.to_string(),
+        span,
+        leading_slash,
+        term: term.ok_or_else(|| Error::UnknownUnitString(expression.to_string()))?,
+    })
+}
+
+impl FromStr for Expression {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse(expression)
+    }
+}
+
+fn build_term(pair: Pair<'_, Rule>) -> Result<TermNode, Error> {
+    let span = Span::from(pair.as_span());
+    let mut pairs = pair.into_inner();
+
+    let component = build_component(pairs.next().ok_or_else(|| unknown(span))?)?;
+
+    let rest = match pairs.next() {
+        Some(operator) => {
+            let kind = match operator.as_rule() {
+                Rule::dot => OperatorKind::Dot,
+                _ => OperatorKind::Slash,
+            };
+            let operator = Operator {
+                kind,
+                span: Span::from(operator.as_span()),
+            };
+            let term = build_term(pairs.next().ok_or_else(|| unknown(span))?)?;
+
+            Some((operator, Box::new(term)))
+        }
+        None => None,
+    };
+
+    Ok(TermNode {
+        span,
+        component,
+        rest,
+    })
+}
+
+fn build_component(pair: Pair<'_, Rule>) -> Result<ComponentNode, Error> {
+    let span = Span::from(pair.as_span());
+    let mut factor = None;
+    let mut basic_component = None;
+
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::factor => factor = Some(build_factor(pair)?),
+            _ => basic_component = Some(build_basic_component(pair)?),
+        }
+    }
+
+    Ok(ComponentNode {
+        span,
+        factor,
+        basic_component: basic_component.ok_or_else(|| unknown(span))?,
+    })
+}
+
+fn build_basic_component(pair: Pair<'_, Rule>) -> Result<BasicComponentNode, Error> {
+    let span = Span::from(pair.as_span());
+    let mut pairs = pair.into_inner();
+    let first = pairs.next().ok_or_else(|| unknown(span))?;
+
+    match first.as_rule() {
+        Rule::annotatable => Ok(BasicComponentNode::Annotatable {
+            span,
+            annotatable: build_annotatable(first)?,
+            annotation: pairs.next().as_ref().map(build_annotation),
+        }),
+        Rule::annotation => Ok(BasicComponentNode::Annotation(build_annotation(&first))),
+        Rule::factor => Ok(BasicComponentNode::Factor(build_factor(first)?)),
+        _ => Ok(BasicComponentNode::Group {
+            span,
+            term: Box::new(build_term(first)?),
+        }),
+    }
+}
+
+fn build_annotatable(pair: Pair<'_, Rule>) -> Result<AnnotatableNode, Error> {
+    let span = Span::from(pair.as_span());
+    let mut pairs = pair.into_inner();
+
+    let simple_unit = pairs.next().ok_or_else(|| unknown(span))?;
+    let text = simple_unit.as_str().to_string();
+    let simple_unit_span = Span::from(simple_unit.as_span());
+
+    let (prefix, atom) = match SimpleUnit::visit(simple_unit)? {
+        SimpleUnit::Prefixed { prefix, atom } => (Some(prefix), Some(atom)),
+        SimpleUnit::Basic { atom } => (None, Some(atom)),
+        SimpleUnit::Unity => (None, None),
+    };
+
+    let exponent = match pairs.next() {
+        Some(exponent) => Some(ExponentNode {
+            span: Span::from(exponent.as_span()),
+            value: Exponent::visit(exponent)?.0,
+        }),
+        None => None,
+    };
+
+    Ok(AnnotatableNode {
+        span,
+        simple_unit: SimpleUnitNode {
+            span: simple_unit_span,
+            text,
+            prefix,
+            atom,
+        },
+        exponent,
+    })
+}
+
+fn build_factor(pair: Pair<'_, Rule>) -> Result<FactorNode, Error> {
+    Ok(FactorNode {
+        span: Span::from(pair.as_span()),
+        value: Factor::visit(pair)?,
+    })
+}
+
+fn build_annotation(pair: &Pair<'_, Rule>) -> AnnotationNode {
+    AnnotationNode {
+        span: Span::from(pair.as_span()),
+        text: pair.as_str().to_string(),
+    }
+}
+
+fn unknown(span: Span) -> Error {
+    Error::UnknownUnitString(format!("{}..{}", span.start, span.end))
+}
+
+//-----------------------------------------------------------------------------
+// Lowering
+//-----------------------------------------------------------------------------
+impl Expression {
+    /// Lowers `self` to the flat list of `Term`s that make up a `Unit`: the
+    /// same `Term`s that parsing the expression as a `Unit` results in.
+    ///
+    #[must_use]
+    pub fn to_terms(&self) -> Vec<Term> {
+        let mut terms = self.term.to_terms();
+
+        if self.leading_slash.is_some() {
+            terms.invert();
+        }
+
+        terms
+    }
+
+    /// Lowers `self` to a `Unit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Overflow` if the resulting `Unit`'s `Composition`
+    /// would overflow.
+    ///
+    pub fn to_unit(&self) -> Result<Unit, Error> {
+        let terms = self.to_terms();
+        validate_composition(&terms)?;
+
+        Ok(Unit::new(terms))
+    }
+}
+
+impl TermNode {
+    fn to_terms(&self) -> Vec<Term> {
+        let mut terms = self.component.to_terms();
+
+        if let Some((operator, rest)) = &self.rest {
+            let mut rest_terms = rest.to_terms();
+
+            if operator.kind == OperatorKind::Slash {
+                rest_terms.invert();
+            }
+
+            terms.extend(rest_terms);
+        }
+
+        terms
+    }
+}
+
+impl ComponentNode {
+    fn to_terms(&self) -> Vec<Term> {
+        let mut terms = self.basic_component.to_terms();
+
+        if let Some(factor) = self.factor {
+            if factor.value != 1 {
+                if let Some(first_term) = terms.first_mut() {
+                    first_term.factor = Some(factor.value);
+                }
+            }
+        }
+
+        terms
+    }
+}
+
+impl BasicComponentNode {
+    fn to_terms(&self) -> Vec<Term> {
+        let empty_term = || Term {
+            factor: None,
+            prefix: None,
+            atom: None,
+            exponent: None,
+            annotation: None,
+        };
+
+        match self {
+            Self::Annotatable {
+                annotatable,
+                annotation,
+                ..
+            } => vec![Term {
+                factor: None,
+                prefix: annotatable.simple_unit.prefix,
+                atom: annotatable.simple_unit.atom,
+                exponent: annotatable.exponent.map(|exponent| exponent.value),
+                annotation: annotation
+                    .as_ref()
+                    .map(|annotation| annotation.text.clone()),
+            }],
+            Self::Annotation(annotation) => vec![Term {
+                annotation: Some(annotation.text.clone()),
+                ..empty_term()
+            }],
+            Self::Factor(factor) => vec![Term {
+                factor: Some(factor.value),
+                ..empty_term()
+            }],
+            Self::Group { term, .. } => {
+                let mut terms = vec![empty_term()];
+                terms.extend(term.to_terms());
+                terms
+            }
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Visitor
+//-----------------------------------------------------------------------------
+/// Walks an `Expression`.
+///
+/// Each method's default implementation visits the node's children (using the
+/// matching `walk_*` function), so implementors only need to override the
+/// methods for the nodes they care about.
+///
+pub trait Visitor {
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_term(&mut self, term: &TermNode) {
+        walk_term(self, term);
+    }
+
+    fn visit_operator(&mut self, _operator: &Operator) {}
+
+    fn visit_component(&mut self, component: &ComponentNode) {
+        walk_component(self, component);
+    }
+
+    fn visit_basic_component(&mut self, basic_component: &BasicComponentNode) {
+        walk_basic_component(self, basic_component);
+    }
+
+    fn visit_annotatable(&mut self, annotatable: &AnnotatableNode) {
+        walk_annotatable(self, annotatable);
+    }
+
+    fn visit_simple_unit(&mut self, _simple_unit: &SimpleUnitNode) {}
+
+    fn visit_exponent(&mut self, _exponent: &ExponentNode) {}
+
+    fn visit_factor(&mut self, _factor: &FactorNode) {}
+
+    fn visit_annotation(&mut self, _annotation: &AnnotationNode) {}
+}
+
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    visitor.visit_term(&expression.term);
+}
+
+pub fn walk_term<V: Visitor + ?Sized>(visitor: &mut V, term: &TermNode) {
+    visitor.visit_component(&term.component);
+
+    if let Some((operator, rest)) = &term.rest {
+        visitor.visit_operator(operator);
+        visitor.visit_term(rest);
+    }
+}
+
+pub fn walk_component<V: Visitor + ?Sized>(visitor: &mut V, component: &ComponentNode) {
+    if let Some(factor) = &component.factor {
+        visitor.visit_factor(factor);
+    }
+
+    visitor.visit_basic_component(&component.basic_component);
+}
+
+pub fn walk_basic_component<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    basic_component: &BasicComponentNode,
+) {
+    match basic_component {
+        BasicComponentNode::Annotatable {
+            annotatable,
+            annotation,
+            ..
+        } => {
+            visitor.visit_annotatable(annotatable);
+
+            if let Some(annotation) = annotation {
+                visitor.visit_annotation(annotation);
+            }
+        }
+        BasicComponentNode::Annotation(annotation) => visitor.visit_annotation(annotation),
+        BasicComponentNode::Factor(factor) => visitor.visit_factor(factor),
+        BasicComponentNode::Group { term, .. } => visitor.visit_term(term),
+    }
+}
+
+pub fn walk_annotatable<V: Visitor + ?Sized>(visitor: &mut V, annotatable: &AnnotatableNode) {
+    visitor.visit_simple_unit(&annotatable.simple_unit);
+
+    if let Some(exponent) = &annotatable.exponent {
+        visitor.visit_exponent(exponent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPRESSIONS: [&str; 16] = [
+        "m",
+        "km2",
+        "/s",
+        "2km-2{meow}/[acr_us].[in_i]",
+        "[acr_us].[in_i]/[acr_us]",
+        "kg/(m.s2)",
+        "2(m.s)",
+        "/100",
+        "{tablet}",
+        "mL{total}/d",
+        "10*3/uL",
+        "10^6.[iU]",
+        "1",
+        "CEL",
+        "m.(g/s)/(L.(mol/h))",
+        "[in_i]/h{tot}",
+    ];
+
+    #[test]
+    fn validate_lowering_matches_parse() {
+        for expression in EXPRESSIONS {
+            let ast = parse(expression).unwrap();
+
+            assert_eq!(
+                ast.to_terms(),
+                crate::parser::parse(expression).unwrap(),
+                "{}",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn validate_spans() {
+        let expression = parse("2km-2{meow}/[acr_us]").unwrap();
+        assert_eq!(expression.span, Span { start: 0, end: 20 });
+        assert!(expression.leading_slash.is_none());
+
+        let component = &expression.term.component;
+        assert_eq!(
+            component.factor.unwrap().span.slice(&expression.source),
+            "2"
+        );
+
+        match &component.basic_component {
+            BasicComponentNode::Annotatable {
+                annotatable,
+                annotation,
+                ..
+            } => {
+                assert_eq!(annotatable.simple_unit.text, "km");
+                assert_eq!(annotatable.simple_unit.span, Span { start: 1, end: 3 });
+                assert_eq!(annotatable.simple_unit.prefix, Some(Prefix::Kilo));
+                assert_eq!(annotatable.simple_unit.atom, Some(Atom::Meter));
+                assert_eq!(
+                    annotatable.exponent,
+                    Some(ExponentNode {
+                        span: Span { start: 3, end: 5 },
+                        value: -2
+                    })
+                );
+
+                let annotation = annotation.as_ref().unwrap();
+                assert_eq!(annotation.text, "meow");
+                assert_eq!(annotation.span, Span { start: 6, end: 10 });
+            }
+            other => panic!("Unexpected node: {:?}", other),
+        }
+
+        let (operator, rest) = expression.term.rest.as_ref().unwrap();
+        assert_eq!(operator.kind, OperatorKind::Slash);
+        assert_eq!(operator.span, Span { start: 11, end: 12 });
+        assert_eq!(rest.span.slice(&expression.source), "[acr_us]");
+    }
+
+    #[test]
+    fn validate_groups_and_leading_slash() {
+        let expression = parse("/(m.s)").unwrap();
+        assert_eq!(expression.leading_slash, Some(Span { start: 0, end: 1 }));
+
+        match &expression.term.component.basic_component {
+            BasicComponentNode::Group { span, term } => {
+                assert_eq!(span.slice(&expression.source), "(m.s)");
+                assert_eq!(term.span.slice(&expression.source), "m.s");
+            }
+            other => panic!("Unexpected node: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validate_original_spelling() {
+        let expression = parse("CEL").unwrap();
+
+        match &expression.term.component.basic_component {
+            BasicComponentNode::Annotatable { annotatable, .. } => {
+                assert_eq!(annotatable.simple_unit.text, "CEL");
+                assert_eq!(annotatable.simple_unit.atom, Some(Atom::DegreeCelsius));
+            }
+            other => panic!("Unexpected node: {:?}", other),
+        }
+
+        assert_eq!(expression.to_unit().unwrap().expression(), "Cel");
+    }
+
+    #[test]
+    fn validate_errors() {
+        assert_eq!(
+            parse("m.foo"),
+            Err(Error::BadFragment {
+                fragment: "foo".to_string(),
+                position: 2
+            })
+        );
+        assert!(matches!(parse("m..s"), Err(Error::UnknownUnitString(_))));
+        assert!(matches!(parse("m99999999999"), Err(Error::Overflow { .. })));
+        assert!(matches!(
+            parse("s2147483647.s2147483647").unwrap().to_unit(),
+            Err(Error::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_visitor() {
+        #[derive(Default)]
+        struct Counter {
+            operators: Vec<OperatorKind>,
+            factors: Vec<u64>,
+            exponents: Vec<i32>,
+            annotations: Vec<String>,
+            groups: usize,
+        }
+
+        impl Visitor for Counter {
+            fn visit_operator(&mut self, operator: &Operator) {
+                self.operators.push(operator.kind);
+            }
+
+            fn visit_basic_component(&mut self, basic_component: &BasicComponentNode) {
+                if matches!(basic_component, BasicComponentNode::Group { .. }) {
+                    self.groups += 1;
+                }
+
+                walk_basic_component(self, basic_component);
+            }
+
+            fn visit_exponent(&mut self, exponent: &ExponentNode) {
+                self.exponents.push(exponent.value);
+            }
+
+            fn visit_factor(&mut self, factor: &FactorNode) {
+                self.factors.push(factor.value);
+            }
+
+            fn visit_annotation(&mut self, annotation: &AnnotationNode) {
+                self.annotations.push(annotation.text.clone());
+            }
+        }
+
+        let expression = parse("2mg{dry}/(10L.h-1)").unwrap();
+        let mut counter = Counter::default();
+        counter.visit_expression(&expression);
+
+        assert_eq!(counter.operators, [OperatorKind::Slash, OperatorKind::Dot]);
+        assert_eq!(counter.factors, [2, 10]);
+        assert_eq!(counter.exponents, [-1]);
+        assert_eq!(counter.annotations, ["dry"]);
+        assert_eq!(counter.groups, 1);
+    }
+}
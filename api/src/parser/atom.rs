@@ -329,6 +329,325 @@ pub enum Atom {
 }
 
 impl Atom {
+    const ALL: &'static [Self] = &[
+        Self::Meter,
+        Self::Second,
+        Self::Gram,
+        Self::Radian,
+        Self::Kelvin,
+        Self::Coulomb,
+        Self::Candela,
+        Self::TheNumberTenForArbitraryPowersStar,
+        Self::TheNumberTenForArbitraryPowersCaret,
+        Self::TheNumberPi,
+        Self::Percent,
+        Self::PartsPerThousand,
+        Self::PartsPerMillion,
+        Self::PartsPerBillion,
+        Self::PartsPerTrillion,
+        Self::Mole,
+        Self::Steradian,
+        Self::Hertz,
+        Self::Newton,
+        Self::Pascal,
+        Self::Joule,
+        Self::Watt,
+        Self::Ampere,
+        Self::Volt,
+        Self::Farad,
+        Self::Ohm,
+        Self::Siemens,
+        Self::Weber,
+        Self::DegreeCelsius,
+        Self::Tesla,
+        Self::Henry,
+        Self::Lumen,
+        Self::Lux,
+        Self::Becquerel,
+        Self::Gray,
+        Self::Sievert,
+        Self::Gon,
+        Self::Degree,
+        Self::MinuteAngle,
+        Self::SecondAngle,
+        Self::Liter,
+        Self::LiterSecondary,
+        Self::Are,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::TropicalYear,
+        Self::MeanJulianYear,
+        Self::MeanGregorianYear,
+        Self::Year,
+        Self::Week,
+        Self::SynodalMonth,
+        Self::MeanJulianMonth,
+        Self::MeanGregorianMonth,
+        Self::Month,
+        Self::Tonne,
+        Self::Bar,
+        Self::UnifiedAtomicMassUnit,
+        Self::Electronvolt,
+        Self::AstronomicUnit,
+        Self::Parsec,
+        Self::VelocityOfLight,
+        Self::PlanckConstant,
+        Self::BoltzmannConstant,
+        Self::PermittivityOfVacuum,
+        Self::PermeabilityOfVacuum,
+        Self::ElementaryCharge,
+        Self::ElectronMass,
+        Self::ProtonMass,
+        Self::NewtonianConstantOfGravitation,
+        Self::StandardAccelerationOfFreeFall,
+        Self::StandardAtmosphere,
+        Self::LightYear,
+        Self::GramForce,
+        Self::PoundForceAvoirdupois,
+        Self::Kayser,
+        Self::Gal,
+        Self::Dyne,
+        Self::Erg,
+        Self::Poise,
+        Self::Biot,
+        Self::Stokes,
+        Self::Maxwell,
+        Self::Gauss,
+        Self::Oersted,
+        Self::Gilbert,
+        Self::Stilb,
+        Self::Lambert,
+        Self::Phot,
+        Self::Curie,
+        Self::Roentgen,
+        Self::RadiationAbsorbedDose,
+        Self::RadiationEquivalentMan,
+        Self::InchInternational,
+        Self::FootInternational,
+        Self::YardInternational,
+        Self::MileInternational,
+        Self::FathomInternational,
+        Self::NauticalMileInternational,
+        Self::KnotInternational,
+        Self::SquareInchInternational,
+        Self::SquareFootInternational,
+        Self::SquareYardInternational,
+        Self::CubicInchInternational,
+        Self::CubicFootInternational,
+        Self::CubicYardInternational,
+        Self::BoardFootInternational,
+        Self::CordInternational,
+        Self::MilInternational,
+        Self::CircularMilInternational,
+        Self::HandInternational,
+        Self::FootUS,
+        Self::YardUS,
+        Self::InchUS,
+        Self::RodUS,
+        Self::GuntersChainUS,
+        Self::LinkForGuntersChainUS,
+        Self::RamdensChainUS,
+        Self::LinkForRamdensChainUS,
+        Self::FathomUS,
+        Self::FurlongUS,
+        Self::MileUS,
+        Self::AcreUS,
+        Self::SquareRodUS,
+        Self::SquareMileUS,
+        Self::Section,
+        Self::Township,
+        Self::MilUS,
+        Self::InchBritish,
+        Self::FootBritish,
+        Self::RodBritish,
+        Self::GuntersChainBritish,
+        Self::LinkForGuntersChainBritish,
+        Self::FathomBritish,
+        Self::PaceBritish,
+        Self::YardBritish,
+        Self::MileBritish,
+        Self::NauticalMileBritish,
+        Self::KnotBritish,
+        Self::AcreBritish,
+        Self::QueenAnnesWineGallonUS,
+        Self::BarrelUS,
+        Self::QuartUS,
+        Self::PintUS,
+        Self::GillUS,
+        Self::FluidOunceUS,
+        Self::FluidDramUS,
+        Self::MinimUS,
+        Self::CordUS,
+        Self::BushelUS,
+        Self::HistoricalWinchesterGallon,
+        Self::PeckUS,
+        Self::DryQuartUS,
+        Self::DryPintUS,
+        Self::TablespoonUS,
+        Self::TeaspoonUS,
+        Self::CupUS,
+        Self::MetricFluidOunce,
+        Self::MetricCup,
+        Self::MetricTeaspoon,
+        Self::MetricTablespoon,
+        Self::GallonBritish,
+        Self::PeckBritish,
+        Self::BushelBritish,
+        Self::QuartBritish,
+        Self::PintBritish,
+        Self::GillBritish,
+        Self::FluidOunceBritish,
+        Self::FluidDramBritish,
+        Self::MinimBritish,
+        Self::Grain,
+        Self::PoundAvoirdupois,
+        Self::OunceAvoirdupois,
+        Self::DramAvoirdupois,
+        Self::ShortHundredweightAvoirdupois,
+        Self::LongHunderdweightAvoirdupois,
+        Self::ShortTonAvoirdupois,
+        Self::LongTonAvoirdupois,
+        Self::StoneAvoirdupois,
+        Self::PennyweightTroy,
+        Self::OunceTroy,
+        Self::PoundTroy,
+        Self::ScrupleApothecaries,
+        Self::DramApothecaries,
+        Self::OunceApothecaries,
+        Self::PoundApothecaries,
+        Self::MetricOunce,
+        Self::Line,
+        Self::Point,
+        Self::Pica,
+        Self::PrintersPoint,
+        Self::PrintersPica,
+        Self::Pied,
+        Self::Pouce,
+        Self::Ligne,
+        Self::Didot,
+        Self::Cicero,
+        Self::DegreeFahrenheit,
+        Self::DegreeRankine,
+        Self::DegreeReaumur,
+        Self::CalorieAt15C,
+        Self::CalorieAt20C,
+        Self::MeanCalorie,
+        Self::InternationalTableCalorie,
+        Self::ThermochemicalCalorie,
+        Self::Calorie,
+        Self::NutritionLabelCalories,
+        Self::BritishThermalUnitAt39F,
+        Self::BritishThermalUnitAt59F,
+        Self::BritishThermalUnitAt60F,
+        Self::MeanBritishThermalUnit,
+        Self::InternationalTableBritishThermalUnit,
+        Self::ThermochemicalBritishThermalUnit,
+        Self::BritishThermalUnit,
+        Self::Horsepower,
+        Self::Tex,
+        Self::Denier,
+        Self::MeterOfWaterColumn,
+        Self::MeterOfMercuryColumn,
+        Self::InchOfWaterColumn,
+        Self::InchOfMercuryColumn,
+        Self::PeripheralVascularResistanceUnit,
+        Self::WoodUnit,
+        Self::Diopter,
+        Self::PrismDiopter,
+        Self::PercentOfSlope,
+        Self::MeshInternational,
+        Self::Charriere,
+        Self::Drop,
+        Self::HounsfieldUnit,
+        Self::MetabolicEquivalent,
+        Self::HomeopathicPotencyOfDecimalSeriesRetired,
+        Self::HomeopathicPotencyOfCentesimalSeriesRetired,
+        Self::HomeopathicPotencyOfMillesimalSeriesRetired,
+        Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired,
+        Self::HomeopathicPotencyOfDecimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfCentesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfMillesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfDecimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfCentesimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfMillesimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries,
+        Self::Equivalents,
+        Self::Osmole,
+        Self::PH,
+        Self::GramPercent,
+        Self::SvedbergUnit,
+        Self::HighPowerField,
+        Self::LowPowerField,
+        Self::Katal,
+        Self::Unit,
+        Self::InternationalUnit,
+        Self::InternationalUnitSecondary,
+        Self::ArbitraryUnit,
+        Self::UnitedStatesPharmacopeiaUnit,
+        Self::GplUnit,
+        Self::MplUnit,
+        Self::AplUnit,
+        Self::BethesdaUnit,
+        Self::AntiFactorXaUnit,
+        Self::ToddUnit,
+        Self::DyeUnit,
+        Self::SomogyiUnit,
+        Self::BodanskyUnit,
+        Self::KingArmstrongUnit,
+        Self::KunkelUnit,
+        Self::MacLaganUnit,
+        Self::TuberculinUnit,
+        Self::CellCultureInfectiousDose,
+        Self::TissueCultureInfectiousDose,
+        Self::EmbryoInfectiousDose,
+        Self::PlaqueFormingUnits,
+        Self::FocusFormingUnits,
+        Self::ColonyFormingUnits,
+        Self::IndexOfReactivity,
+        Self::BioequivalentAllergenUnit,
+        Self::AllergenUnit,
+        Self::AllergenUnitForAmbrosiaArtemisiifolia,
+        Self::ProteinNitrogenUnit,
+        Self::LimitOfFlocculation,
+        Self::DAntigenUnit,
+        Self::FibrinogenEquivalentUnit,
+        Self::ElisaUnit,
+        Self::EhrlichUnit,
+        Self::Neper,
+        Self::Bel,
+        Self::BelSoundPressure,
+        Self::BelVolt,
+        Self::BelMillivolt,
+        Self::BelMicrovolt,
+        Self::Bel10Nanovolt,
+        Self::BelWatt,
+        Self::BelKilowatt,
+        Self::Stere,
+        Self::Angstrom,
+        Self::Barn,
+        Self::TechnicalAtmosphere,
+        Self::Mho,
+        Self::PoundPerSqareInch,
+        Self::Circle,
+        Self::Spere,
+        Self::MetricCarat,
+        Self::CaratOfGoldAlloys,
+        Self::Smoot,
+        Self::MeterPerSquareSecondsPerSquareRootOfHertz,
+        Self::BitLogarithmusDualis,
+        Self::Bit,
+        Self::Byte,
+        Self::Baud,
+    ];
+
+    /// Iterates over every `Atom`, in the order they're defined.
+    ///
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
     pub(crate) fn definition(self) -> Definition {
         let result = match self {
             Self::Meter => Ok(Definition::default()),
@@ -1122,6 +1441,370 @@ impl Atom {
             Self::Baud => Property::SignalTransmissionRate,
         }
     }
+
+    /// Is this one of UCUM's seven base units, which every other unit is
+    /// defined in terms of?
+    ///
+    #[must_use]
+    pub const fn is_base_unit(self) -> bool {
+        match self {
+            Self::Meter => true,
+            Self::Second => true,
+            Self::Gram => true,
+            Self::Radian => true,
+            Self::Kelvin => true,
+            Self::Coulomb => true,
+            Self::Candela => true,
+            _ => false,
+        }
+    }
+
+    /// The unit expression this atom is defined with, as it's written in UCUM
+    /// (ex. `"cm"` for `[in_i]`, `"cel(1 K)"` for `Cel`); the number it's
+    /// multiplied by is `definition_value()`. Base units aren't defined in
+    /// terms of anything, so they return `None`.
+    ///
+    #[must_use]
+    pub const fn definition_expression(self) -> Option<&'static str> {
+        match self {
+            Self::TheNumberTenForArbitraryPowersStar => Some("1"),
+            Self::TheNumberTenForArbitraryPowersCaret => Some("1"),
+            Self::TheNumberPi => Some("1"),
+            Self::Percent => Some("10*-2"),
+            Self::PartsPerThousand => Some("10*-3"),
+            Self::PartsPerMillion => Some("10*-6"),
+            Self::PartsPerBillion => Some("10*-9"),
+            Self::PartsPerTrillion => Some("10*-12"),
+            Self::Mole => Some("10*23"),
+            Self::Steradian => Some("rad2"),
+            Self::Hertz => Some("s-1"),
+            Self::Newton => Some("kg.m/s2"),
+            Self::Pascal => Some("N/m2"),
+            Self::Joule => Some("N.m"),
+            Self::Watt => Some("J/s"),
+            Self::Ampere => Some("C/s"),
+            Self::Volt => Some("J/C"),
+            Self::Farad => Some("C/V"),
+            Self::Ohm => Some("V/A"),
+            Self::Siemens => Some("Ohm-1"),
+            Self::Weber => Some("V.s"),
+            Self::DegreeCelsius => Some("cel(1 K)"),
+            Self::Tesla => Some("Wb/m2"),
+            Self::Henry => Some("Wb/A"),
+            Self::Lumen => Some("cd.sr"),
+            Self::Lux => Some("lm/m2"),
+            Self::Becquerel => Some("s-1"),
+            Self::Gray => Some("J/kg"),
+            Self::Sievert => Some("J/kg"),
+            Self::Gon => Some("deg"),
+            Self::Degree => Some("[pi].rad/360"),
+            Self::MinuteAngle => Some("deg/60"),
+            Self::SecondAngle => Some("'/60"),
+            Self::Liter => Some("dm3"),
+            Self::LiterSecondary => Some("l"),
+            Self::Are => Some("m2"),
+            Self::Minute => Some("s"),
+            Self::Hour => Some("min"),
+            Self::Day => Some("h"),
+            Self::TropicalYear => Some("d"),
+            Self::MeanJulianYear => Some("d"),
+            Self::MeanGregorianYear => Some("d"),
+            Self::Year => Some("a_j"),
+            Self::Week => Some("d"),
+            Self::SynodalMonth => Some("d"),
+            Self::MeanJulianMonth => Some("a_j/12"),
+            Self::MeanGregorianMonth => Some("a_g/12"),
+            Self::Month => Some("mo_j"),
+            Self::Tonne => Some("kg"),
+            Self::Bar => Some("Pa"),
+            Self::UnifiedAtomicMassUnit => Some("g"),
+            Self::Electronvolt => Some("[e].V"),
+            Self::AstronomicUnit => Some("Mm"),
+            Self::Parsec => Some("m"),
+            Self::VelocityOfLight => Some("m/s"),
+            Self::PlanckConstant => Some("J.s"),
+            Self::BoltzmannConstant => Some("J/K"),
+            Self::PermittivityOfVacuum => Some("F/m"),
+            Self::PermeabilityOfVacuum => Some("4.[pi].10*-7.N/A2"),
+            Self::ElementaryCharge => Some("C"),
+            Self::ElectronMass => Some("g"),
+            Self::ProtonMass => Some("g"),
+            Self::NewtonianConstantOfGravitation => Some("m3.kg-1.s-2"),
+            Self::StandardAccelerationOfFreeFall => Some("m/s2"),
+            Self::StandardAtmosphere => Some("Pa"),
+            Self::LightYear => Some("[c].a_j"),
+            Self::GramForce => Some("g.[g]"),
+            Self::PoundForceAvoirdupois => Some("[lb_av].[g]"),
+            Self::Kayser => Some("cm-1"),
+            Self::Gal => Some("cm/s2"),
+            Self::Dyne => Some("g.cm/s2"),
+            Self::Erg => Some("dyn.cm"),
+            Self::Poise => Some("dyn.s/cm2"),
+            Self::Biot => Some("A"),
+            Self::Stokes => Some("cm2/s"),
+            Self::Maxwell => Some("Wb"),
+            Self::Gauss => Some("T"),
+            Self::Oersted => Some("/[pi].A/m"),
+            Self::Gilbert => Some("Oe.cm"),
+            Self::Stilb => Some("cd/cm2"),
+            Self::Lambert => Some("cd/cm2/[pi]"),
+            Self::Phot => Some("lx"),
+            Self::Curie => Some("Bq"),
+            Self::Roentgen => Some("C/kg"),
+            Self::RadiationAbsorbedDose => Some("erg/g"),
+            Self::RadiationEquivalentMan => Some("RAD"),
+            Self::InchInternational => Some("cm"),
+            Self::FootInternational => Some("[in_i]"),
+            Self::YardInternational => Some("[ft_i]"),
+            Self::MileInternational => Some("[ft_i]"),
+            Self::FathomInternational => Some("[ft_i]"),
+            Self::NauticalMileInternational => Some("m"),
+            Self::KnotInternational => Some("[nmi_i]/h"),
+            Self::SquareInchInternational => Some("[in_i]2"),
+            Self::SquareFootInternational => Some("[ft_i]2"),
+            Self::SquareYardInternational => Some("[yd_i]2"),
+            Self::CubicInchInternational => Some("[in_i]3"),
+            Self::CubicFootInternational => Some("[ft_i]3"),
+            Self::CubicYardInternational => Some("[yd_i]3"),
+            Self::BoardFootInternational => Some("[in_i]3"),
+            Self::CordInternational => Some("[ft_i]3"),
+            Self::MilInternational => Some("[in_i]"),
+            Self::CircularMilInternational => Some("[pi]/4.[mil_i]2"),
+            Self::HandInternational => Some("[in_i]"),
+            Self::FootUS => Some("m/3937"),
+            Self::YardUS => Some("[ft_us]"),
+            Self::InchUS => Some("[ft_us]/12"),
+            Self::RodUS => Some("[ft_us]"),
+            Self::GuntersChainUS => Some("[rd_us]"),
+            Self::LinkForGuntersChainUS => Some("[ch_us]/100"),
+            Self::RamdensChainUS => Some("[ft_us]"),
+            Self::LinkForRamdensChainUS => Some("[rch_us]/100"),
+            Self::FathomUS => Some("[ft_us]"),
+            Self::FurlongUS => Some("[rd_us]"),
+            Self::MileUS => Some("[fur_us]"),
+            Self::AcreUS => Some("[rd_us]2"),
+            Self::SquareRodUS => Some("[rd_us]2"),
+            Self::SquareMileUS => Some("[mi_us]2"),
+            Self::Section => Some("[mi_us]2"),
+            Self::Township => Some("[sct]"),
+            Self::MilUS => Some("[in_us]"),
+            Self::InchBritish => Some("cm"),
+            Self::FootBritish => Some("[in_br]"),
+            Self::RodBritish => Some("[ft_br]"),
+            Self::GuntersChainBritish => Some("[rd_br]"),
+            Self::LinkForGuntersChainBritish => Some("[ch_br]/100"),
+            Self::FathomBritish => Some("[ft_br]"),
+            Self::PaceBritish => Some("[ft_br]"),
+            Self::YardBritish => Some("[ft_br]"),
+            Self::MileBritish => Some("[ft_br]"),
+            Self::NauticalMileBritish => Some("[ft_br]"),
+            Self::KnotBritish => Some("[nmi_br]/h"),
+            Self::AcreBritish => Some("[yd_br]2"),
+            Self::QueenAnnesWineGallonUS => Some("[in_i]3"),
+            Self::BarrelUS => Some("[gal_us]"),
+            Self::QuartUS => Some("[gal_us]/4"),
+            Self::PintUS => Some("[qt_us]/2"),
+            Self::GillUS => Some("[pt_us]/4"),
+            Self::FluidOunceUS => Some("[gil_us]/4"),
+            Self::FluidDramUS => Some("[foz_us]/8"),
+            Self::MinimUS => Some("[fdr_us]/60"),
+            Self::CordUS => Some("[ft_i]3"),
+            Self::BushelUS => Some("[in_i]3"),
+            Self::HistoricalWinchesterGallon => Some("[bu_us]/8"),
+            Self::PeckUS => Some("[bu_us]/4"),
+            Self::DryQuartUS => Some("[pk_us]/8"),
+            Self::DryPintUS => Some("[dqt_us]/2"),
+            Self::TablespoonUS => Some("[foz_us]/2"),
+            Self::TeaspoonUS => Some("[tbs_us]/3"),
+            Self::CupUS => Some("[tbs_us]"),
+            Self::MetricFluidOunce => Some("mL"),
+            Self::MetricCup => Some("mL"),
+            Self::MetricTeaspoon => Some("mL"),
+            Self::MetricTablespoon => Some("mL"),
+            Self::GallonBritish => Some("l"),
+            Self::PeckBritish => Some("[gal_br]"),
+            Self::BushelBritish => Some("[pk_br]"),
+            Self::QuartBritish => Some("[gal_br]/4"),
+            Self::PintBritish => Some("[qt_br]/2"),
+            Self::GillBritish => Some("[pt_br]/4"),
+            Self::FluidOunceBritish => Some("[gil_br]/5"),
+            Self::FluidDramBritish => Some("[foz_br]/8"),
+            Self::MinimBritish => Some("[fdr_br]/60"),
+            Self::Grain => Some("mg"),
+            Self::PoundAvoirdupois => Some("[gr]"),
+            Self::OunceAvoirdupois => Some("[lb_av]/16"),
+            Self::DramAvoirdupois => Some("[oz_av]/16"),
+            Self::ShortHundredweightAvoirdupois => Some("[lb_av]"),
+            Self::LongHunderdweightAvoirdupois => Some("[lb_av]"),
+            Self::ShortTonAvoirdupois => Some("[scwt_av]"),
+            Self::LongTonAvoirdupois => Some("[lcwt_av]"),
+            Self::StoneAvoirdupois => Some("[lb_av]"),
+            Self::PennyweightTroy => Some("[gr]"),
+            Self::OunceTroy => Some("[pwt_tr]"),
+            Self::PoundTroy => Some("[oz_tr]"),
+            Self::ScrupleApothecaries => Some("[gr]"),
+            Self::DramApothecaries => Some("[sc_ap]"),
+            Self::OunceApothecaries => Some("[dr_ap]"),
+            Self::PoundApothecaries => Some("[oz_ap]"),
+            Self::MetricOunce => Some("g"),
+            Self::Line => Some("[in_i]/12"),
+            Self::Point => Some("[lne]/6"),
+            Self::Pica => Some("[pnt]"),
+            Self::PrintersPoint => Some("[in_i]"),
+            Self::PrintersPica => Some("[pnt_pr]"),
+            Self::Pied => Some("cm"),
+            Self::Pouce => Some("[pied]/12"),
+            Self::Ligne => Some("[pouce]/12"),
+            Self::Didot => Some("[ligne]/6"),
+            Self::Cicero => Some("[didot]"),
+            Self::DegreeFahrenheit => Some("degf(5 K/9)"),
+            Self::DegreeRankine => Some("K/9"),
+            Self::DegreeReaumur => Some("degre(5 K/4)"),
+            Self::CalorieAt15C => Some("J"),
+            Self::CalorieAt20C => Some("J"),
+            Self::MeanCalorie => Some("J"),
+            Self::InternationalTableCalorie => Some("J"),
+            Self::ThermochemicalCalorie => Some("J"),
+            Self::Calorie => Some("cal_th"),
+            Self::NutritionLabelCalories => Some("kcal_th"),
+            Self::BritishThermalUnitAt39F => Some("kJ"),
+            Self::BritishThermalUnitAt59F => Some("kJ"),
+            Self::BritishThermalUnitAt60F => Some("kJ"),
+            Self::MeanBritishThermalUnit => Some("kJ"),
+            Self::InternationalTableBritishThermalUnit => Some("kJ"),
+            Self::ThermochemicalBritishThermalUnit => Some("kJ"),
+            Self::BritishThermalUnit => Some("[Btu_th]"),
+            Self::Horsepower => Some("[ft_i].[lbf_av]/s"),
+            Self::Tex => Some("g/km"),
+            Self::Denier => Some("g/9/km"),
+            Self::MeterOfWaterColumn => Some("kPa"),
+            Self::MeterOfMercuryColumn => Some("kPa"),
+            Self::InchOfWaterColumn => Some("m[H2O].[in_i]/m"),
+            Self::InchOfMercuryColumn => Some("m[Hg].[in_i]/m"),
+            Self::PeripheralVascularResistanceUnit => Some("mm[Hg].s/ml"),
+            Self::WoodUnit => Some("mm[Hg].min/L"),
+            Self::Diopter => Some("/m"),
+            Self::PrismDiopter => Some("100tan(1 rad)"),
+            Self::PercentOfSlope => Some("100tan(1 rad)"),
+            Self::MeshInternational => Some("/[in_i]"),
+            Self::Charriere => Some("mm/3"),
+            Self::Drop => Some("ml/20"),
+            Self::HounsfieldUnit => Some("1"),
+            Self::MetabolicEquivalent => Some("mL/min/kg"),
+            Self::HomeopathicPotencyOfDecimalSeriesRetired => Some("hpX(1 1)"),
+            Self::HomeopathicPotencyOfCentesimalSeriesRetired => Some("hpC(1 1)"),
+            Self::HomeopathicPotencyOfMillesimalSeriesRetired => Some("hpM(1 1)"),
+            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => Some("hpQ(1 1)"),
+            Self::HomeopathicPotencyOfDecimalHahnemannianSeries => Some("1"),
+            Self::HomeopathicPotencyOfCentesimalHahnemannianSeries => Some("1"),
+            Self::HomeopathicPotencyOfMillesimalHahnemannianSeries => Some("1"),
+            Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries => Some("1"),
+            Self::HomeopathicPotencyOfDecimalKorsakovianSeries => Some("1"),
+            Self::HomeopathicPotencyOfCentesimalKorsakovianSeries => Some("1"),
+            Self::HomeopathicPotencyOfMillesimalKorsakovianSeries => Some("1"),
+            Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries => Some("1"),
+            Self::Equivalents => Some("mol"),
+            Self::Osmole => Some("mol"),
+            Self::PH => Some("pH(1 mol/l)"),
+            Self::GramPercent => Some("g/dl"),
+            Self::SvedbergUnit => Some("10*-13.s"),
+            Self::HighPowerField => Some("1"),
+            Self::LowPowerField => Some("1"),
+            Self::Katal => Some("mol/s"),
+            Self::Unit => Some("umol/min"),
+            Self::InternationalUnit => Some("1"),
+            Self::InternationalUnitSecondary => Some("[iU]"),
+            Self::ArbitraryUnit => Some("1"),
+            Self::UnitedStatesPharmacopeiaUnit => Some("1"),
+            Self::GplUnit => Some("1"),
+            Self::MplUnit => Some("1"),
+            Self::AplUnit => Some("1"),
+            Self::BethesdaUnit => Some("1"),
+            Self::AntiFactorXaUnit => Some("1"),
+            Self::ToddUnit => Some("1"),
+            Self::DyeUnit => Some("1"),
+            Self::SomogyiUnit => Some("1"),
+            Self::BodanskyUnit => Some("1"),
+            Self::KingArmstrongUnit => Some("1"),
+            Self::KunkelUnit => Some("1"),
+            Self::MacLaganUnit => Some("1"),
+            Self::TuberculinUnit => Some("1"),
+            Self::CellCultureInfectiousDose => Some("1"),
+            Self::TissueCultureInfectiousDose => Some("1"),
+            Self::EmbryoInfectiousDose => Some("1"),
+            Self::PlaqueFormingUnits => Some("1"),
+            Self::FocusFormingUnits => Some("1"),
+            Self::ColonyFormingUnits => Some("1"),
+            Self::IndexOfReactivity => Some("1"),
+            Self::BioequivalentAllergenUnit => Some("1"),
+            Self::AllergenUnit => Some("1"),
+            Self::AllergenUnitForAmbrosiaArtemisiifolia => Some("1"),
+            Self::ProteinNitrogenUnit => Some("1"),
+            Self::LimitOfFlocculation => Some("1"),
+            Self::DAntigenUnit => Some("1"),
+            Self::FibrinogenEquivalentUnit => Some("1"),
+            Self::ElisaUnit => Some("1"),
+            Self::EhrlichUnit => Some("1"),
+            Self::Neper => Some("ln(1 1)"),
+            Self::Bel => Some("lg(1 1)"),
+            Self::BelSoundPressure => Some("2lg(2 10*-5.Pa)"),
+            Self::BelVolt => Some("2lg(1 V)"),
+            Self::BelMillivolt => Some("2lg(1 mV)"),
+            Self::BelMicrovolt => Some("2lg(1 uV)"),
+            Self::Bel10Nanovolt => Some("2lg(10 nV)"),
+            Self::BelWatt => Some("lg(1 W)"),
+            Self::BelKilowatt => Some("lg(1 kW)"),
+            Self::Stere => Some("m3"),
+            Self::Angstrom => Some("nm"),
+            Self::Barn => Some("fm2"),
+            Self::TechnicalAtmosphere => Some("kgf/cm2"),
+            Self::Mho => Some("S"),
+            Self::PoundPerSqareInch => Some("[lbf_av]/[in_i]2"),
+            Self::Circle => Some("[pi].rad"),
+            Self::Spere => Some("[pi].sr"),
+            Self::MetricCarat => Some("g"),
+            Self::CaratOfGoldAlloys => Some("/24"),
+            Self::Smoot => Some("[in_i]"),
+            Self::MeterPerSquareSecondsPerSquareRootOfHertz => Some("sqrt(1 m2/s4/Hz)"),
+            Self::BitLogarithmusDualis => Some("ld(1 1)"),
+            Self::Bit => Some("1"),
+            Self::Byte => Some("bit"),
+            Self::Baud => Some("/s"),
+            _ => None,
+        }
+    }
+
+    /// The name of the UCUM function that converts to and from a special
+    /// unit (ex. `"Cel"`, `"lg"`, `"pH"`), or `None` for units that aren't
+    /// special.
+    ///
+    #[must_use]
+    pub const fn special_function_name(self) -> Option<&'static str> {
+        match self {
+            Self::DegreeCelsius => Some("Cel"),
+            Self::DegreeFahrenheit => Some("degF"),
+            Self::DegreeReaumur => Some("degRe"),
+            Self::PrismDiopter => Some("tanTimes100"),
+            Self::PercentOfSlope => Some("100tan"),
+            Self::HomeopathicPotencyOfDecimalSeriesRetired => Some("hpX"),
+            Self::HomeopathicPotencyOfCentesimalSeriesRetired => Some("hpC"),
+            Self::HomeopathicPotencyOfMillesimalSeriesRetired => Some("hpM"),
+            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => Some("hpQ"),
+            Self::PH => Some("pH"),
+            Self::Neper => Some("ln"),
+            Self::Bel => Some("lg"),
+            Self::BelSoundPressure => Some("lgTimes2"),
+            Self::BelVolt => Some("lgTimes2"),
+            Self::BelMillivolt => Some("lgTimes2"),
+            Self::BelMicrovolt => Some("lgTimes2"),
+            Self::Bel10Nanovolt => Some("lgTimes2"),
+            Self::BelWatt => Some("lg"),
+            Self::BelKilowatt => Some("lg"),
+            Self::MeterPerSquareSecondsPerSquareRootOfHertz => Some("sqrt"),
+            Self::BitLogarithmusDualis => Some("ld"),
+            _ => None,
+        }
+    }
 }
 
 impl UcumSymbol for Atom {
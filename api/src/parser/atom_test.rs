@@ -216,6 +216,56 @@ fn validate_definitions_base_atoms() {
     }
 }
 
+#[test]
+fn validate_is_base_unit() {
+    let base_atoms: Vec<Atom> = Atom::iter().filter(|atom| atom.is_base_unit()).collect();
+
+    assert_eq!(
+        base_atoms,
+        vec![
+            Atom::Meter,
+            Atom::Second,
+            Atom::Gram,
+            Atom::Radian,
+            Atom::Kelvin,
+            Atom::Coulomb,
+            Atom::Candela,
+        ]
+    );
+    assert!(!Atom::Liter.is_base_unit());
+}
+
+#[test]
+fn validate_definition_expression() {
+    assert_eq!(Atom::Meter.definition_expression(), None);
+    assert_eq!(Atom::InchInternational.definition_expression(), Some("cm"));
+    assert_eq!(Atom::Degree.definition_expression(), Some("[pi].rad/360"));
+    assert_eq!(
+        Atom::DegreeCelsius.definition_expression(),
+        Some("cel(1 K)")
+    );
+
+    for atom in Atom::iter().filter(|atom| !atom.is_base_unit()) {
+        assert!(atom.definition_expression().is_some(), "{atom:?}");
+    }
+}
+
+#[test]
+fn validate_special_function_name() {
+    assert_eq!(Atom::DegreeCelsius.special_function_name(), Some("Cel"));
+    assert_eq!(Atom::PH.special_function_name(), Some("pH"));
+    assert_eq!(Atom::Neper.special_function_name(), Some("ln"));
+    assert_eq!(Atom::Meter.special_function_name(), None);
+
+    for atom in Atom::iter() {
+        assert_eq!(
+            atom.special_function_name().is_some(),
+            atom.is_special(),
+            "{atom:?}"
+        );
+    }
+}
+
 validate_definition!(
     validate_definition_acre_us,
     AcreUS,
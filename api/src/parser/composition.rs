@@ -1,5 +1,9 @@
-use super::Dimension;
-use std::{fmt, ops::Mul};
+use super::{error::map_int_error, Dimension, Error};
+use std::{
+    fmt,
+    ops::{Div, Mul},
+    str::FromStr,
+};
 
 type Exponent = i32;
 
@@ -56,6 +60,30 @@ pub const KINEMATIC_VISCOSITY: Composition =
 pub const SPECIFIC_HEAT: Composition =
     Composition::new_any(None, Some(2), None, None, None, Some(-1), Some(-2));
 
+/// The well-known `Composition`s, along with a human name for each.
+///
+const NAMED_COMPOSITIONS: [(&str, Composition); 19] = [
+    ("dimensionless", DIMLESS),
+    ("electric charge", ELECTRIC_CHARGE),
+    ("length", LENGTH),
+    ("area", AREA),
+    ("volume", VOLUME),
+    ("mass", MASS),
+    ("luminous intensity", LUMINOUS_INTENSITY),
+    ("temperature", TEMPERATURE),
+    ("time", TIME),
+    ("velocity", VELOCITY),
+    ("acceleration", ACCELERATION),
+    ("density", DENSITY),
+    ("force", FORCE),
+    ("pressure", PRESSURE),
+    ("energy", ENEGERY),
+    ("power", POWER),
+    ("dynamic viscosity", DYNAMIC_VISCOSITY),
+    ("kinematic viscosity", KINEMATIC_VISCOSITY),
+    ("specific heat", SPECIFIC_HEAT),
+];
+
 /// A `Composition` represents the makeup of a `Unit`'s dimensions; only
 /// dimensions and each `Unit`s `Term`'s exponent. For example, "m" would
 /// effectively have the composition string of "L"; "m2" would be "L2"; "1/m2"
@@ -75,22 +103,6 @@ pub struct Composition {
     time: Option<Exponent>,
 }
 
-macro_rules! def_mul_exponent {
-    ($meth_name:ident, $composition_method:ident) => {
-        fn $meth_name(
-            original_value: Option<i32>,
-            exponent: i32,
-            new_composition: &mut Composition,
-        ) {
-            if let Some(self_exponent) = original_value {
-                let new_exponent = self_exponent * exponent;
-
-                new_composition.$composition_method = set_exponent(new_exponent);
-            }
-        }
-    };
-}
-
 macro_rules! insert_exponent {
     ($composition:expr, $method:ident, $exponent:expr) => {
         match $composition.$method {
@@ -104,22 +116,6 @@ macro_rules! insert_exponent {
     };
 }
 
-macro_rules! def_add_dimension {
-    ($meth_name:ident, $composition_method:ident) => {
-        fn $meth_name(
-            original_value: Option<i32>,
-            rhs_composition: Composition,
-            new_composition: &mut Composition,
-        ) {
-            new_composition.$composition_method = if let Some(self_value) = original_value {
-                insert_exponent!(rhs_composition, $composition_method, self_value)
-            } else {
-                rhs_composition.$composition_method
-            }
-        }
-    };
-}
-
 impl Composition {
     #[must_use]
     pub const fn new(dimension: Dimension, exponent: i32) -> Self {
@@ -275,6 +271,29 @@ impl Composition {
         }
     }
 
+    /// The exponent of `dimension` in `self`, or `None` if `self` doesn't
+    /// include `dimension`.
+    ///
+    /// ```rust
+    /// use wise_units::{composition, Dimension};
+    ///
+    /// assert_eq!(composition::VELOCITY.exponent(Dimension::Time), Some(-1));
+    /// assert_eq!(composition::VELOCITY.exponent(Dimension::Mass), None);
+    /// ```
+    ///
+    #[must_use]
+    pub const fn exponent(&self, dimension: Dimension) -> Option<i32> {
+        match dimension {
+            Dimension::ElectricCharge => self.electric_charge,
+            Dimension::Length => self.length,
+            Dimension::LuminousIntensity => self.luminous_intensity,
+            Dimension::Mass => self.mass,
+            Dimension::PlaneAngle => self.plane_angle,
+            Dimension::Temperature => self.temperature,
+            Dimension::Time => self.time,
+        }
+    }
+
     fn insert_electric_charge(&mut self, exponent: i32) {
         self.electric_charge = insert_exponent!(self, electric_charge, exponent);
     }
@@ -303,6 +322,93 @@ impl Composition {
         self.time = insert_exponent!(self, time, exponent);
     }
 
+    /// The human name of `self`, if it's one of the well-known `Composition`s
+    /// (ex. `"velocity"` for `L.T-1`).
+    ///
+    /// ```rust
+    /// use wise_units::{composition, Composition};
+    ///
+    /// assert_eq!(composition::PRESSURE.name(), Some("pressure"));
+    /// assert_eq!(Composition::new_time(-1).name(), None);
+    /// ```
+    ///
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_COMPOSITIONS
+            .iter()
+            .find(|(_, composition)| composition == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Looks up a well-known `Composition` by its human name (see `name()`).
+    ///
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COMPOSITIONS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, composition)| *composition)
+    }
+
+    /// Raises each dimension's exponent to the power of `exponent`. Same as
+    /// multiplying by an `i32`.
+    ///
+    #[must_use]
+    pub fn pow(self, exponent: i32) -> Self {
+        self * exponent
+    }
+
+    /// The reciprocal `Composition` (ex. `T-1` for `T`).
+    ///
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self * -1
+    }
+
+    /// Same as `self * rhs`, but returns `None` if any exponent overflows.
+    ///
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_zip(rhs, i32::checked_add)
+    }
+
+    /// Same as `self / rhs`, but returns `None` if any exponent overflows.
+    ///
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_zip(rhs, i32::checked_sub)
+    }
+
+    /// Same as `self.pow(exponent)`, but returns `None` if any exponent
+    /// overflows.
+    ///
+    #[must_use]
+    pub fn checked_pow(self, exponent: i32) -> Option<Self> {
+        self.checked_zip(Self::default(), |lhs, _| lhs.checked_mul(exponent))
+    }
+
+    /// Applies `f` to each pair of dimension exponents (with missing exponents
+    /// treated as 0), bailing out if `f` returns `None` for any of them.
+    ///
+    fn checked_zip<F>(self, rhs: Self, f: F) -> Option<Self>
+    where
+        F: Fn(i32, i32) -> Option<i32>,
+    {
+        let apply = |lhs: Option<i32>, rhs: Option<i32>| -> Option<Option<i32>> {
+            f(lhs.unwrap_or(0), rhs.unwrap_or(0)).map(set_exponent)
+        };
+
+        Some(Self {
+            electric_charge: apply(self.electric_charge, rhs.electric_charge)?,
+            length: apply(self.length, rhs.length)?,
+            luminous_intensity: apply(self.luminous_intensity, rhs.luminous_intensity)?,
+            mass: apply(self.mass, rhs.mass)?,
+            plane_angle: apply(self.plane_angle, rhs.plane_angle)?,
+            temperature: apply(self.temperature, rhs.temperature)?,
+            time: apply(self.time, rhs.time)?,
+        })
+    }
+
     #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.electric_charge.is_none()
@@ -350,36 +456,85 @@ fn push_display_expression(
     }
 }
 
+// impl FromStr
+/// Parses strings in the same format that `Display` renders (ex. `M.L-1.T-2`).
+/// An empty string or `"1"` parses as a dimensionless `Composition`. Repeated
+/// dimensions are combined (ex. `L.L2` is `L3`), and return an
+/// `Error::Overflow` if that overflows.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use wise_units::{composition, Composition};
+///
+/// assert_eq!(Composition::from_str("M.L-1.T-2").unwrap(), composition::PRESSURE);
+/// assert!(Composition::from_str("M.X2").is_err());
+/// ```
+///
+impl FromStr for Composition {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let mut composition = Self::default();
+
+        if expression.is_empty() || expression == "1" {
+            return Ok(composition);
+        }
+
+        let mut position = 0;
+
+        for fragment in expression.split('.') {
+            let bad_fragment = || Error::BadFragment {
+                fragment: fragment.to_string(),
+                position,
+            };
+
+            let mut chars = fragment.chars();
+
+            let dimension = match chars.next().ok_or_else(bad_fragment)? {
+                'Q' => Dimension::ElectricCharge,
+                'L' => Dimension::Length,
+                'F' => Dimension::LuminousIntensity,
+                'M' => Dimension::Mass,
+                'A' => Dimension::PlaneAngle,
+                'C' => Dimension::Temperature,
+                'T' => Dimension::Time,
+                _ => return Err(bad_fragment()),
+            };
+
+            let exponent = match chars.as_str() {
+                "" => 1,
+                digits if digits.starts_with('+') => return Err(bad_fragment()),
+                digits => digits
+                    .parse::<i32>()
+                    .map_err(|error| map_int_error(error, fragment))?,
+            };
+
+            composition = composition
+                .checked_mul(Self::new(dimension, exponent))
+                .ok_or_else(|| Error::Overflow {
+                    term: fragment.to_string(),
+                })?;
+            position += fragment.len() + 1;
+        }
+
+        Ok(composition)
+    }
+}
+
 // impl Mul
-/// Used for combining two `Compositions`.
+/// Used for combining two `Compositions`. Panics if any exponent overflows;
+/// see `checked_mul()` for a non-panicking version.
 ///
 #[cfg_attr(feature = "cargo-clippy", allow(clippy::suspicious_arithmetic_impl))]
 impl Mul for Composition {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut new_composition = Self::default();
-
-        add_electric_charge(self.electric_charge, rhs, &mut new_composition);
-        add_length(self.length, rhs, &mut new_composition);
-        add_luminous_intensity(self.luminous_intensity, rhs, &mut new_composition);
-        add_mass(self.mass, rhs, &mut new_composition);
-        add_plane_angle(self.plane_angle, rhs, &mut new_composition);
-        add_temperature(self.temperature, rhs, &mut new_composition);
-        add_time(self.time, rhs, &mut new_composition);
-
-        new_composition
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| panic!("Composition exponent overflow: ({}) * ({})", self, rhs))
     }
 }
 
-def_add_dimension!(add_electric_charge, electric_charge);
-def_add_dimension!(add_length, length);
-def_add_dimension!(add_luminous_intensity, luminous_intensity);
-def_add_dimension!(add_mass, mass);
-def_add_dimension!(add_plane_angle, plane_angle);
-def_add_dimension!(add_temperature, temperature);
-def_add_dimension!(add_time, time);
-
 /// Used essentially for calculating the `Composition` of a `Term`. When a `Term` has an exponent
 /// set, the `Term`'s `Atom`'s `Composition` must be multiplied by it. For example, if a `Term`
 /// has `Atom` `Are` (which is a square meter) and `exponent` 3, the `Composition` should be `L6`
@@ -399,32 +554,32 @@ def_add_dimension!(add_time, time);
 /// assert_eq!(&t.composition().to_string(), "L6");
 /// ```
 ///
+/// Panics if any exponent overflows; see `checked_pow()` for a non-panicking
+/// version.
+///
 impl Mul<i32> for Composition {
     type Output = Self;
 
     fn mul(self, rhs: i32) -> Self::Output {
-        let mut new_composition = Self::default();
+        self.checked_pow(rhs)
+            .unwrap_or_else(|| panic!("Composition exponent overflow: ({}) * {}", self, rhs))
+    }
+}
 
-        mul_electric_charge(self.electric_charge, rhs, &mut new_composition);
-        mul_length(self.length, rhs, &mut new_composition);
-        mul_luminous_intensity(self.luminous_intensity, rhs, &mut new_composition);
-        mul_mass(self.mass, rhs, &mut new_composition);
-        mul_plane_angle(self.plane_angle, rhs, &mut new_composition);
-        mul_temperature(self.temperature, rhs, &mut new_composition);
-        mul_time(self.time, rhs, &mut new_composition);
+// impl Div
+/// Used for combining two `Compositions`, where `rhs`'s exponents are
+/// subtracted from `self`'s.
+///
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::suspicious_arithmetic_impl))]
+impl Div for Composition {
+    type Output = Self;
 
-        new_composition
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .unwrap_or_else(|| panic!("Composition exponent overflow: ({}) / ({})", self, rhs))
     }
 }
 
-def_mul_exponent!(mul_electric_charge, electric_charge);
-def_mul_exponent!(mul_length, length);
-def_mul_exponent!(mul_luminous_intensity, luminous_intensity);
-def_mul_exponent!(mul_mass, mass);
-def_mul_exponent!(mul_plane_angle, plane_angle);
-def_mul_exponent!(mul_temperature, temperature);
-def_mul_exponent!(mul_time, time);
-
 /// Used internally for disallowing setting any of the dimensions' exponents to 0 (it should
 /// be `None` in that case).
 ///
@@ -438,7 +593,10 @@ const fn set_exponent(exponent: i32) -> Option<i32> {
 
 #[cfg(test)]
 mod tests {
-    use super::{super::Dimension, Composition};
+    use super::{
+        super::{Dimension, Error},
+        Composition,
+    };
 
     #[test]
     fn validate_default() {
@@ -722,4 +880,138 @@ mod tests {
         let product = subject * -2;
         assert_eq!(product.mass, Some(-4));
     }
+
+    #[test]
+    fn validate_from_str() {
+        use crate::composition;
+        use std::str::FromStr;
+
+        assert_eq!(Composition::from_str("").unwrap(), Composition::default());
+        assert_eq!(Composition::from_str("1").unwrap(), Composition::default());
+        assert_eq!(Composition::from_str("L").unwrap(), composition::LENGTH);
+        assert_eq!(
+            Composition::from_str("L.T-1").unwrap(),
+            composition::VELOCITY
+        );
+        assert_eq!(
+            Composition::from_str("T-1.L").unwrap(),
+            composition::VELOCITY
+        );
+        assert_eq!(Composition::from_str("L.L2").unwrap(), composition::VOLUME);
+
+        let subject = Composition::from_str("Q-3.L-5.F-7.M-1.A-6.C-2.T-4").unwrap();
+        assert_eq!(subject.to_string(), "Q-3.L-5.F-7.M-1.A-6.C-2.T-4");
+
+        assert_eq!(
+            Composition::from_str("L.X2").unwrap_err(),
+            Error::BadFragment {
+                fragment: "X2".to_string(),
+                position: 2
+            }
+        );
+        assert!(Composition::from_str("L.").is_err());
+        assert!(Composition::from_str("L+2").is_err());
+        assert!(matches!(
+            Composition::from_str("Lx").unwrap_err(),
+            Error::UnableToParseInteger(_)
+        ));
+    }
+
+    #[test]
+    fn validate_from_str_overflow() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            Composition::from_str("L2147483647.L").unwrap_err(),
+            Error::Overflow {
+                term: "L".to_string()
+            }
+        );
+        assert_eq!(
+            Composition::from_str("L99999999999").unwrap_err(),
+            Error::Overflow {
+                term: "L99999999999".to_string()
+            }
+        );
+        assert_eq!(
+            Composition::from_str("M.T-99999999999").unwrap_err(),
+            Error::Overflow {
+                term: "T-99999999999".to_string()
+            }
+        );
+        assert_eq!(
+            Composition::from_str("T-2147483648.T-1").unwrap_err(),
+            Error::Overflow {
+                term: "T-1".to_string()
+            }
+        );
+        assert_eq!(
+            Composition::from_str("L2147483647.L-1").unwrap(),
+            Composition::new_length(2_147_483_646)
+        );
+    }
+
+    #[test]
+    fn validate_div() {
+        use crate::composition;
+
+        assert_eq!(
+            composition::LENGTH / composition::TIME,
+            composition::VELOCITY
+        );
+        assert_eq!(
+            composition::VELOCITY / composition::TIME,
+            composition::ACCELERATION
+        );
+        assert!((composition::MASS / composition::MASS).is_empty());
+    }
+
+    #[test]
+    fn validate_pow_and_inverse() {
+        use crate::composition;
+
+        assert_eq!(composition::LENGTH.pow(3), composition::VOLUME);
+        assert!(composition::LENGTH.pow(0).is_empty());
+        assert_eq!(composition::TIME.inverse().to_string(), "T-1");
+        assert_eq!(
+            composition::VELOCITY.inverse().inverse(),
+            composition::VELOCITY
+        );
+    }
+
+    #[test]
+    fn validate_checked() {
+        use crate::composition;
+
+        let big = Composition::new(Dimension::Mass, i32::MAX);
+
+        assert_eq!(big.checked_mul(big), None);
+        assert_eq!(big.checked_pow(2), None);
+        assert_eq!(big.inverse().checked_div(big), None);
+        assert_eq!(big.checked_div(big), Some(Composition::default()));
+        assert_eq!(
+            composition::LENGTH.checked_div(composition::TIME),
+            Some(composition::VELOCITY)
+        );
+        assert_eq!(
+            composition::AREA.checked_pow(-1).unwrap().to_string(),
+            "L-2"
+        );
+    }
+
+    #[test]
+    fn validate_name() {
+        use crate::composition;
+
+        assert_eq!(composition::VELOCITY.name(), Some("velocity"));
+        assert_eq!(composition::ENEGERY.name(), Some("energy"));
+        assert_eq!(Composition::default().name(), Some("dimensionless"));
+        assert_eq!(Composition::new_time(-1).name(), None);
+
+        assert_eq!(
+            Composition::from_name("Pressure"),
+            Some(composition::PRESSURE)
+        );
+        assert_eq!(Composition::from_name("nope"), None);
+    }
 }
@@ -39,6 +39,10 @@ impl Definition {
         &self.terms
     }
 
+    pub(crate) const fn is_special(&self) -> bool {
+        self.function_set.is_some()
+    }
+
     pub(crate) fn is_unity(&self) -> bool {
         self.terms.len() == 1 && self.terms[0].is_unity()
     }
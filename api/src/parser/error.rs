@@ -2,6 +2,7 @@ use crate::parser::{
     symbols::symbol_parser::Rule as SymbolRule, terms::term_parser::Rule as TermRule,
 };
 use pest::error::Error as PestError;
+use std::num::{IntErrorKind, ParseIntError};
 
 /// Errors when trying to convert between types that aren't commensurable.
 ///
@@ -18,7 +19,13 @@ pub enum Error {
 
     #[error(transparent)]
     #[cfg_attr(feature = "serde", serde(serialize_with = "stringify"))]
-    UnableToParseInteger(#[from] std::num::ParseIntError),
+    UnableToParseInteger(#[from] ParseIntError),
+
+    /// Indicates a factor, exponent, or dimension in `term` is too large to be
+    /// represented.
+    ///
+    #[error("Integer overflow in term: {term}")]
+    Overflow { term: String },
 
     #[error("Unknown unit string fragment: {fragment} ({position})")]
     BadFragment { fragment: String, position: usize },
@@ -29,6 +36,18 @@ pub enum Error {
     UnknownUnitString(String),
 }
 
+/// Integers that are too large to fit in their type are reported as
+/// `Error::Overflow`, instead of an opaque `ParseIntError`.
+///
+pub(super) fn map_int_error(error: ParseIntError, string: &str) -> Error {
+    match error.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Error::Overflow {
+            term: string.to_string(),
+        },
+        _ => Error::from(error),
+    }
+}
+
 #[cfg(feature = "serde")]
 fn stringify<E, S>(error: E, s: S) -> Result<S::Ok, S::Error>
 where
@@ -14,6 +14,7 @@ const CENTI: f64 = 1.0e-2;
 const DECI: f64 = 1.0e-1;
 const DEKA: f64 = 1.0e1;
 const EXA: f64 = 1.0e18;
+const EXBI: f64 = 1_152_921_504_606_846_976.0;
 const FEMTO: f64 = 1.0e-15;
 const GIBI: f64 = 1_073_741_824.0;
 const GIGA: f64 = 1.0e9;
@@ -25,12 +26,19 @@ const MEGA: f64 = 1.0e6;
 const MICRO: f64 = 1.0e-6;
 const MILLI: f64 = 1.0e-3;
 const NANO: f64 = 1.0e-9;
+const PEBI: f64 = 1_125_899_906_842_624.0;
 const PETA: f64 = 1.0e15;
 const PICO: f64 = 1.0e-12;
+const QUECTO: f64 = 1.0e-30;
+const QUETTA: f64 = 1.0e30;
+const RONNA: f64 = 1.0e27;
+const RONTO: f64 = 1.0e-27;
 const TEBI: f64 = 1_099_511_627_776.0;
 const TERA: f64 = 1.0e12;
+const YOBI: f64 = 1_208_925_819_614_629_174_706_176.0;
 const YOCTO: f64 = 1.0e-24;
 const YOTTA: f64 = 1.0e24;
+const ZEBI: f64 = 1_180_591_620_717_411_303_424.0;
 const ZEPTO: f64 = 1.0e-21;
 const ZETTA: f64 = 1.0e21;
 
@@ -46,6 +54,7 @@ pub enum Prefix {
     Deci,
     Deka,
     Exa,
+    Exbi,
     Femto,
     Gibi,
     Giga,
@@ -57,16 +66,66 @@ pub enum Prefix {
     Micro,
     Milli,
     Nano,
+    Pebi,
     Peta,
     Pico,
+    Quecto,
+    Quetta,
+    Ronna,
+    Ronto,
     Tebi,
     Tera,
+    Yobi,
     Yocto,
     Yotta,
+    Zebi,
     Zepto,
     Zetta,
 }
 
+impl Prefix {
+    const ALL: &'static [Self] = &[
+        Self::Atto,
+        Self::Centi,
+        Self::Deci,
+        Self::Deka,
+        Self::Exa,
+        Self::Exbi,
+        Self::Femto,
+        Self::Gibi,
+        Self::Giga,
+        Self::Hecto,
+        Self::Kibi,
+        Self::Kilo,
+        Self::Mebi,
+        Self::Mega,
+        Self::Micro,
+        Self::Milli,
+        Self::Nano,
+        Self::Pebi,
+        Self::Peta,
+        Self::Pico,
+        Self::Quecto,
+        Self::Quetta,
+        Self::Ronna,
+        Self::Ronto,
+        Self::Tebi,
+        Self::Tera,
+        Self::Yobi,
+        Self::Yocto,
+        Self::Yotta,
+        Self::Zebi,
+        Self::Zepto,
+        Self::Zetta,
+    ];
+
+    /// Iterates over every `Prefix`, in the order they're defined.
+    ///
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+}
+
 impl UcumSymbol for Prefix {
     fn classification(&self) -> Classification {
         Classification::Si
@@ -79,6 +138,7 @@ impl UcumSymbol for Prefix {
             Self::Deci => vec!["deci"],
             Self::Deka => vec!["deka"],
             Self::Exa => vec!["exa"],
+            Self::Exbi => vec!["exbi"],
             Self::Femto => vec!["femto"],
             Self::Gibi => vec!["gibi"],
             Self::Giga => vec!["giga"],
@@ -90,12 +150,19 @@ impl UcumSymbol for Prefix {
             Self::Micro => vec!["micro"],
             Self::Milli => vec!["milli"],
             Self::Nano => vec!["nano"],
+            Self::Pebi => vec!["pebi"],
             Self::Peta => vec!["peta"],
             Self::Pico => vec!["pico"],
+            Self::Quecto => vec!["quecto"],
+            Self::Quetta => vec!["quetta"],
+            Self::Ronna => vec!["ronna"],
+            Self::Ronto => vec!["ronto"],
             Self::Tebi => vec!["tebi"],
             Self::Tera => vec!["tera"],
+            Self::Yobi => vec!["yobi"],
             Self::Yocto => vec!["yocto"],
             Self::Yotta => vec!["yotta"],
+            Self::Zebi => vec!["zebi"],
             Self::Zepto => vec!["zepto"],
             Self::Zetta => vec!["zetta"],
         }
@@ -108,6 +175,7 @@ impl UcumSymbol for Prefix {
             Self::Deci => "d",
             Self::Deka => "da",
             Self::Exa => "E",
+            Self::Exbi => "Ei",
             Self::Femto => "f",
             Self::Gibi => "Gi",
             Self::Giga => "G",
@@ -119,12 +187,19 @@ impl UcumSymbol for Prefix {
             Self::Micro => "u",
             Self::Milli => "m",
             Self::Nano => "n",
+            Self::Pebi => "Pi",
             Self::Peta => "P",
             Self::Pico => "p",
+            Self::Quecto => "q",
+            Self::Quetta => "Q",
+            Self::Ronna => "R",
+            Self::Ronto => "r",
             Self::Tebi => "Ti",
             Self::Tera => "T",
+            Self::Yobi => "Yi",
             Self::Yocto => "y",
             Self::Yotta => "Y",
+            Self::Zebi => "Zi",
             Self::Zepto => "z",
             Self::Zetta => "Z",
         }
@@ -145,6 +220,7 @@ impl UcumSymbol for Prefix {
             Self::Deci => "D",
             Self::Deka => "DA",
             Self::Exa => "EX",
+            Self::Exbi => "EIB",
             Self::Femto => "F",
             Self::Gibi => "GIB",
             Self::Giga => "GA",
@@ -156,12 +232,19 @@ impl UcumSymbol for Prefix {
             Self::Micro => "U",
             Self::Milli => "M",
             Self::Nano => "N",
+            Self::Pebi => "PIB",
             Self::Peta => "PT",
             Self::Pico => "P",
+            Self::Quecto => "QO",
+            Self::Quetta => "QA",
+            Self::Ronna => "RA",
+            Self::Ronto => "RO",
             Self::Tebi => "TIB",
             Self::Tera => "TR",
+            Self::Yobi => "YIB",
             Self::Yocto => "YO",
             Self::Yotta => "YA",
+            Self::Zebi => "ZIB",
             Self::Zepto => "ZO",
             Self::Zetta => "ZA",
         };
@@ -178,6 +261,7 @@ impl UcumSymbol for Prefix {
             Self::Deci => DECI,
             Self::Deka => DEKA,
             Self::Exa => EXA,
+            Self::Exbi => EXBI,
             Self::Femto => FEMTO,
             Self::Gibi => GIBI,
             Self::Giga => GIGA,
@@ -189,12 +273,19 @@ impl UcumSymbol for Prefix {
             Self::Micro => MICRO,
             Self::Milli => MILLI,
             Self::Nano => NANO,
+            Self::Pebi => PEBI,
             Self::Peta => PETA,
             Self::Pico => PICO,
+            Self::Quecto => QUECTO,
+            Self::Quetta => QUETTA,
+            Self::Ronna => RONNA,
+            Self::Ronto => RONTO,
             Self::Tebi => TEBI,
             Self::Tera => TERA,
+            Self::Yobi => YOBI,
             Self::Yocto => YOCTO,
             Self::Yotta => YOTTA,
+            Self::Zebi => ZEBI,
             Self::Zepto => ZEPTO,
             Self::Zetta => ZETTA,
         }
@@ -215,21 +306,31 @@ impl Visit<Rule> for Prefix {
             Rule::pri_deci | Rule::sec_deci => Self::Deci,
             Rule::pri_deka | Rule::sec_deka => Self::Deka,
             Rule::pri_exa | Rule::sec_exa => Self::Exa,
+            Rule::pri_exbi | Rule::sec_exbi => Self::Exbi,
             Rule::pri_femto | Rule::sec_femto => Self::Femto,
             Rule::pri_gibi | Rule::sec_gibi => Self::Gibi,
             Rule::pri_giga | Rule::sec_giga => Self::Giga,
             Rule::pri_hecto | Rule::sec_hecto => Self::Hecto,
+            Rule::pri_kibi | Rule::sec_kibi => Self::Kibi,
             Rule::pri_kilo | Rule::sec_kilo => Self::Kilo,
             Rule::pri_mebi | Rule::sec_mebi => Self::Mebi,
             Rule::pri_mega | Rule::sec_mega => Self::Mega,
             Rule::pri_micro | Rule::sec_micro => Self::Micro,
             Rule::pri_milli | Rule::sec_milli => Self::Milli,
             Rule::pri_nano | Rule::sec_nano => Self::Nano,
+            Rule::pri_pebi | Rule::sec_pebi => Self::Pebi,
             Rule::pri_peta | Rule::sec_peta => Self::Peta,
+            Rule::pri_pico | Rule::sec_pico => Self::Pico,
+            Rule::pri_quecto | Rule::sec_quecto => Self::Quecto,
+            Rule::pri_quetta | Rule::sec_quetta => Self::Quetta,
+            Rule::pri_ronna | Rule::sec_ronna => Self::Ronna,
+            Rule::pri_ronto | Rule::sec_ronto => Self::Ronto,
             Rule::pri_tebi | Rule::sec_tebi => Self::Tebi,
             Rule::pri_tera | Rule::sec_tera => Self::Tera,
+            Rule::pri_yobi | Rule::sec_yobi => Self::Yobi,
             Rule::pri_yocto | Rule::sec_yocto => Self::Yocto,
             Rule::pri_yotta | Rule::sec_yotta => Self::Yotta,
+            Rule::pri_zebi | Rule::sec_zebi => Self::Zebi,
             Rule::pri_zepto | Rule::sec_zepto => Self::Zepto,
             Rule::pri_zetta | Rule::sec_zetta => Self::Zetta,
             _ => {
@@ -251,7 +352,7 @@ impl fmt::Display for Prefix {
 #[cfg(test)]
 mod tests {
     use super::Prefix;
-    use crate::parser::ucum_symbol::UcumSymbol;
+    use crate::{parser::ucum_symbol::UcumSymbol, Convertible, Measurement};
     use approx::{assert_relative_eq, assert_ulps_eq};
 
     macro_rules! validate_value {
@@ -274,32 +375,67 @@ mod tests {
     }
 
     validate_values!(
-        validate_value_atto,  Atto,  1.0e-18;
-        validate_value_centi, Centi, 1.0e-2;
-        validate_value_deci,  Deci,  1.0e-1;
-        validate_value_deka,  Deka,  1.0e1;
-        validate_value_exa,   Exa,   1.0e18;
-        validate_value_femto, Femto, 1.0e-15;
-        validate_value_gibi,  Gibi,  1_073_741_824.0;
-        validate_value_giga,  Giga,  1.0e9;
-        validate_value_hecto, Hecto, 1.0e2;
-        validate_value_kibi,  Kibi,  1024.0;
-        validate_value_kilo,  Kilo,  1.0e3;
-        validate_value_mebi,  Mebi,  1_048_576.0;
-        validate_value_mega,  Mega,  1.0e6;
-        validate_value_micro, Micro, 1.0e-6;
-        validate_value_milli, Milli, 1.0e-3;
-        validate_value_nano,  Nano,  1.0e-9;
-        validate_value_peta,  Peta,  1.0e15;
-        validate_value_pico,  Pico,  1.0e-12;
-        validate_value_tebi,  Tebi,  1_099_511_627_776.0;
-        validate_value_tera,  Tera,  1.0e12;
-        validate_value_yocto, Yocto, 1.0e-24;
-        validate_value_yotta, Yotta, 1.0e24;
-        validate_value_zepto, Zepto, 1.0e-21;
-        validate_value_zetta, Zetta, 1.0e21;
+        validate_value_atto,   Atto,   1.0e-18;
+        validate_value_centi,  Centi,  1.0e-2;
+        validate_value_deci,   Deci,   1.0e-1;
+        validate_value_deka,   Deka,   1.0e1;
+        validate_value_exa,    Exa,    1.0e18;
+        validate_value_exbi,   Exbi,   1_152_921_504_606_846_976.0;
+        validate_value_femto,  Femto,  1.0e-15;
+        validate_value_gibi,   Gibi,   1_073_741_824.0;
+        validate_value_giga,   Giga,   1.0e9;
+        validate_value_hecto,  Hecto,  1.0e2;
+        validate_value_kibi,   Kibi,   1024.0;
+        validate_value_kilo,   Kilo,   1.0e3;
+        validate_value_mebi,   Mebi,   1_048_576.0;
+        validate_value_mega,   Mega,   1.0e6;
+        validate_value_micro,  Micro,  1.0e-6;
+        validate_value_milli,  Milli,  1.0e-3;
+        validate_value_nano,   Nano,   1.0e-9;
+        validate_value_pebi,   Pebi,   1_125_899_906_842_624.0;
+        validate_value_peta,   Peta,   1.0e15;
+        validate_value_pico,   Pico,   1.0e-12;
+        validate_value_quecto, Quecto, 1.0e-30;
+        validate_value_quetta, Quetta, 1.0e30;
+        validate_value_ronna,  Ronna,  1.0e27;
+        validate_value_ronto,  Ronto,  1.0e-27;
+        validate_value_tebi,   Tebi,   1_099_511_627_776.0;
+        validate_value_tera,   Tera,   1.0e12;
+        validate_value_yobi,   Yobi,   1_208_925_819_614_629_174_706_176.0;
+        validate_value_yocto,  Yocto,  1.0e-24;
+        validate_value_yotta,  Yotta,  1.0e24;
+        validate_value_zebi,   Zebi,   1_180_591_620_717_411_303_424.0;
+        validate_value_zepto,  Zepto,  1.0e-21;
+        validate_value_zetta,  Zetta,  1.0e21;
     );
 
+    #[test]
+    fn validate_conversions() {
+        let converted = Measurement::try_new(1.0, "TiBy")
+            .unwrap()
+            .convert_to("GiBy")
+            .unwrap();
+        assert_ulps_eq!(converted.value(), 1024.0);
+
+        let converted = Measurement::try_new(1.0, "YIBBY")
+            .unwrap()
+            .convert_to("ZiBy")
+            .unwrap();
+        assert_ulps_eq!(converted.value(), 1024.0);
+
+        let converted = Measurement::try_new(1.0, "Qg")
+            .unwrap()
+            .convert_to("Rg")
+            .unwrap();
+        assert_relative_eq!(converted.value(), 1000.0);
+
+        let converted = Measurement::try_new(1.0, "rm")
+            .unwrap()
+            .convert_to("qm")
+            .unwrap();
+        assert_relative_eq!(converted.value(), 1000.0);
+    }
+
     #[test]
     fn validate_display() {
         let prefix = Prefix::Kilo;
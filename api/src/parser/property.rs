@@ -10,7 +10,7 @@ use std::fmt;
 /// HTML spec, but is used throughout the
 /// [XML description](http://unitsofmeasure.org/ucum-essence.xml).
 ///
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Property {
     Acceleration,
     Acidity,
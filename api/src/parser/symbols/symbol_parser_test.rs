@@ -188,3 +188,35 @@ fn valid_prefix_and_atom_with_possible_matching_atoms() {
         ]
     }
 }
+
+// The binary prefixes start with the same letter as a decimal prefix (ex. "Ki"
+// and "k", "Mi" and "M"), so they have to be tried first; otherwise "MiBy"
+// matches "M" => "mega" and fails on "iBy".
+#[test]
+fn valid_binary_prefix_and_atom() {
+    parses_to! {
+        parser: SymbolParser,
+        input: "MiBy",
+        rule: Rule::symbol,
+        tokens: [
+            symbol(0, 4, [
+                   pri_prefix(0, 2, [pri_mebi(0, 2)]),
+                   pri_atom(2, 4, [pri_byte(2, 4)]),
+                   EOI(4, 4)
+            ])
+        ]
+    }
+
+    parses_to! {
+        parser: SymbolParser,
+        input: "ZIBBY",
+        rule: Rule::symbol,
+        tokens: [
+            symbol(0, 5, [
+                   sec_prefix(0, 3, [sec_zebi(0, 3)]),
+                   sec_atom(3, 5, [sec_byte(3, 5)]),
+                   EOI(5, 5)
+            ])
+        ]
+    }
+}
@@ -8,6 +8,8 @@ mod partial_eq;
 mod reducible;
 mod ucum_unit;
 
+pub(crate) use self::composable::validate_composition;
+
 use crate::parser::{Atom, Prefix};
 
 /// A Term makes up an Atom (at its core) along with any Atom modifiers
@@ -17,7 +19,7 @@ use crate::parser::{Atom, Prefix};
 ///
 #[derive(Clone, Debug, Eq, Default)]
 pub struct Term {
-    pub factor: Option<u32>,
+    pub factor: Option<u64>,
     pub prefix: Option<Prefix>,
     pub atom: Option<Atom>,
     pub exponent: Option<i32>,
@@ -59,7 +61,7 @@ impl Term {
     ///
     #[must_use]
     pub fn is_unity(&self) -> bool {
-        self.factor == Some(1_u32)
+        self.factor == Some(1_u64)
             && self.exponent.is_none()
             && self.atom.is_none()
             && self.prefix.is_none()
@@ -104,7 +106,7 @@ impl Term {
         }
     }
 
-    pub fn factor_and_is_not_one<F: FnOnce(u32)>(&self, f: F) {
+    pub fn factor_and_is_not_one<F: FnOnce(u64)>(&self, f: F) {
         if let Some(factor) = self.factor {
             if factor != 1 {
                 f(factor);
@@ -113,9 +115,18 @@ impl Term {
     }
 
     #[must_use]
-    pub fn factor_as_u32(&self) -> u32 {
+    pub fn factor_as_u64(&self) -> u64 {
         self.factor.unwrap_or(1)
     }
+
+    /// Factors are now `u64`s; factors that don't fit in a `u32` are saturated
+    /// to `u32::MAX`.
+    ///
+    #[deprecated(since = "0.23.0", note = "use `factor_as_u64()`")]
+    #[must_use]
+    pub fn factor_as_u32(&self) -> u32 {
+        u32::try_from(self.factor_as_u64()).unwrap_or(u32::MAX)
+    }
 }
 
 #[cfg(test)]
@@ -127,4 +138,18 @@ mod tests {
         let term = Term::new_unity();
         assert_eq!(term.to_string(), "1");
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn validate_factor_as_u32() {
+        let mut term = Term::new_unity();
+        assert_eq!(term.factor_as_u32(), 1);
+
+        term.factor = Some(1000);
+        assert_eq!(term.factor_as_u32(), 1000);
+
+        term.factor = Some(u64::from(u32::MAX) + 1);
+        assert_eq!(term.factor_as_u32(), u32::MAX);
+        assert_eq!(term.factor_as_u64(), u64::from(u32::MAX) + 1);
+    }
 }
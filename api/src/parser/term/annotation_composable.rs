@@ -1,13 +1,17 @@
-use crate::parser::{
-    annotation_composition::{AnnotationComposable, AnnotationComposition},
-    Term,
+use crate::{
+    annotation_policy::annotation_key,
+    parser::{
+        annotation_composition::{AnnotationComposable, AnnotationComposition},
+        Term,
+    },
 };
 
 /// Similar to `Composable`, this is only to allow for checking compatibility on `Unit`s whose
 /// `Term`s have annotations. For those cases, we want to be able to ensure that, for example,
 /// `m{foo}` is not comparable to `m{bar}`. This implementation treats each `Term`s `annotation`
 /// as its own `Dimension` of sorts, allowing `m2{foo}/m{foo}` to be comparable to `m{foo}`, since
-/// they have equivalent `AnnotationComposable`s.
+/// they have equivalent `AnnotationComposable`s. Annotations are keyed according to the current
+/// `AnnotationPolicy`.
 ///
 impl<'a> AnnotationComposable for &'a [Term] {
     fn annotation_composition(self) -> Option<AnnotationComposition> {
@@ -15,8 +19,9 @@ impl<'a> AnnotationComposable for &'a [Term] {
             .iter()
             .filter_map(|term| {
                 term.annotation
-                    .as_ref()
-                    .map(|annotation| (annotation.clone(), term.exponent.unwrap_or(1)))
+                    .as_deref()
+                    .and_then(annotation_key)
+                    .map(|key| (key.into_owned(), term.exponent.unwrap_or(1)))
             })
             .fold(AnnotationComposition::new(), |mut map, (key, exponent)| {
                 let _ = map
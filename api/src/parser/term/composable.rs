@@ -1,5 +1,5 @@
 use super::Term;
-use crate::parser::{Composable, Composition};
+use crate::parser::{Composable, Composition, Error};
 
 impl Composable for Term {
     /// Combines the `Composition` from the `Term`'s `Atom` with its own `exponent` to build a
@@ -25,6 +25,53 @@ impl Composable for Term {
     }
 }
 
+impl Term {
+    /// Same as `composition()`, but returns `None` if multiplying the `Atom`'s
+    /// dimensions by the `exponent` overflows.
+    ///
+    pub(crate) fn checked_composition(&self) -> Option<Composition> {
+        match (self.atom, self.exponent) {
+            (Some(atom), Some(exponent)) => atom.composition().checked_pow(exponent),
+            _ => Some(self.composition()),
+        }
+    }
+}
+
+/// The largest (absolute) exponent of any dimension in any `Atom`'s
+/// `Composition`.
+///
+const MAX_ATOM_DIMENSION_EXPONENT: i64 = 16;
+
+/// Makes sure the `Composition` of each of `terms`, and of all of them
+/// combined, can be calculated without overflowing.
+///
+pub(crate) fn validate_composition(terms: &[Term]) -> Result<(), Error> {
+    // Calculating an `Atom`'s `Composition` means parsing its definition, which
+    // is relatively expensive, so only do so when the exponents are large
+    // enough that the result could possibly overflow.
+    let max_exponent_sum: i64 = terms
+        .iter()
+        .map(|term| i64::from(term.exponent.unwrap_or(1)).abs() * MAX_ATOM_DIMENSION_EXPONENT)
+        .sum();
+
+    if max_exponent_sum <= i64::from(i32::MAX) {
+        return Ok(());
+    }
+
+    let overflow = |term: &Term| Error::Overflow {
+        term: term.to_string(),
+    };
+
+    terms
+        .iter()
+        .try_fold(Composition::default(), |acc, term| {
+            term.checked_composition()
+                .and_then(|composition| acc.checked_mul(composition))
+                .ok_or_else(|| overflow(term))
+        })
+        .map(|_| ())
+}
+
 impl Composable for Vec<Term> {
     fn composition(&self) -> Composition {
         self.iter()
@@ -96,4 +143,18 @@ mod tests {
         term!(Kilo, Meter, factor: 10, exponent: -1),
         Composition::new(Dimension::Length, -1)
     );
+
+    #[test]
+    fn validate_max_atom_dimension_exponent() {
+        #[allow(clippy::cast_possible_truncation)]
+        let limit = (i64::from(i32::MAX) / super::MAX_ATOM_DIMENSION_EXPONENT) as i32;
+
+        for atom in Atom::iter() {
+            assert!(
+                atom.composition().checked_pow(limit).is_some(),
+                "{:?} has a dimension exponent larger than the max",
+                atom
+            );
+        }
+    }
 }
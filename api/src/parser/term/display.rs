@@ -23,7 +23,7 @@ fn extract_term_string(term: &Term) -> String {
     term_string
 }
 
-fn extract_term_string_factor(term_string: &mut String, term_factor: Option<u32>) {
+fn extract_term_string_factor(term_string: &mut String, term_factor: Option<u64>) {
     if let Some(factor) = term_factor {
         if factor != 1 {
             term_string.push_str(&factor.to_string());
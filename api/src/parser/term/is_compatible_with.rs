@@ -1,4 +1,5 @@
 use crate::{
+    annotation_policy::annotations_match,
     is_compatible_with::IsCompatibleWith,
     parser::{annotation_composition::AnnotationComposable, Composable, Term},
 };
@@ -32,9 +33,14 @@ use crate::{
 /// More info at nih.gov, [here](https://ucum.nlm.nih.gov/ucum-service.html) (look under the
 /// "Annotations" section for starters).
 ///
+/// How annotations are compared depends on the current `AnnotationPolicy` (see
+/// `annotation_policy::with_annotation_policy()`); the above describes the default,
+/// `AnnotationPolicy::Strict`.
+///
 impl IsCompatibleWith for Term {
     fn is_compatible_with(&self, rhs: &Self) -> bool {
-        self.composition() == rhs.composition() && self.annotation == rhs.annotation
+        self.composition() == rhs.composition()
+            && annotations_match(self.annotation.as_deref(), rhs.annotation.as_deref())
     }
 }
 
@@ -29,17 +29,20 @@ impl Reducible for Vec<Term> {
     }
 }
 
+// Factors beyond 2^53 can't be represented exactly as an `f64`; that's fine
+// here, since the result is a floating point scalar anyway.
+#[allow(clippy::cast_precision_loss)]
 fn combine_term_values(
     calculated_atom: f64,
     calculated_prefix: f64,
-    factor: Option<u32>,
+    factor: Option<u64>,
     exponent: Option<i32>,
 ) -> f64 {
     let a_p_product = calculated_atom * calculated_prefix;
 
     match factor {
         Some(f) => {
-            let product = a_p_product * f64::from(f);
+            let product = a_p_product * f as f64;
 
             match exponent {
                 Some(e) => product.powi(e),
@@ -12,10 +12,12 @@ pub(self) mod finishable;
 pub(self) mod main_term;
 pub(self) mod simple_unit;
 
+pub(in crate::parser) use self::{exponent::Exponent, factor::Factor, simple_unit::SimpleUnit};
+
 use self::{
     annotatable::Annotatable, annotation::Annotation, ast_term::AstTerm,
-    basic_component::BasicComponent, component::Component, digits::Digits, exponent::Exponent,
-    factor::Factor, finishable::Finishable, main_term::MainTerm, simple_unit::SimpleUnit,
+    basic_component::BasicComponent, component::Component, digits::Digits, finishable::Finishable,
+    main_term::MainTerm,
 };
 use crate::parser::{terms::term_parser::Rule, Atom, Error, Prefix, Term, Visit};
 use pest::iterators::{Pair, Pairs};
@@ -101,6 +103,12 @@ mod tests {
         term!(Meter, factor: 2)
     );
     validate_interpret!(validate_interpret_kilometer, "km", term!(Kilo, Meter));
+    validate_interpret!(validate_interpret_picometer, "pm", term!(Pico, Meter));
+    validate_interpret!(validate_interpret_quettagram, "Qg", term!(Quetta, Gram));
+    validate_interpret!(validate_interpret_rontogram, "rg", term!(Ronto, Gram));
+    validate_interpret!(validate_interpret_kibibyte, "KiBy", term!(Kibi, Byte));
+    validate_interpret!(validate_interpret_pebibyte, "PiBy", term!(Pebi, Byte));
+    validate_interpret!(validate_interpret_yobibit, "Yibit", term!(Yobi, Bit));
 
     // Slash terms
     validate_interpret!(
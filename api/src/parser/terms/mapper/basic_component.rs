@@ -3,7 +3,7 @@ use crate::parser::terms::term_parser::Rule;
 use pest::iterators::Pair;
 
 pub(super) struct BasicComponent {
-    pub(super) factor: Option<u32>,
+    pub(super) factor: Option<u64>,
     pub(super) annotatable: Option<Annotatable>,
     pub(super) annotation: Option<String>,
     pub(super) terms: Vec<Term>,
@@ -68,7 +68,7 @@ impl Visit<Rule> for BasicComponent {
 
 enum FirstToken {
     Annotatable(Annotatable),
-    Factor(u32),
+    Factor(u64),
 }
 
 impl Finishable for BasicComponent {
@@ -3,7 +3,7 @@ use crate::parser::terms::term_parser::Rule;
 use pest::iterators::Pair;
 
 pub(super) struct Component {
-    pub(super) factor: Option<u32>,
+    pub(super) factor: Option<u64>,
     pub(super) terms: Vec<Term>,
 }
 
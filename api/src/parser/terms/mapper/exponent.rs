@@ -2,7 +2,7 @@ use super::{Digits, Error, Visit};
 use crate::parser::terms::term_parser::Rule as TermRule;
 use pest::iterators::Pair;
 
-pub(super) struct Exponent(pub(super) i32);
+pub(in crate::parser) struct Exponent(pub(in crate::parser) i32);
 
 impl Visit<TermRule> for Exponent {
     fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
@@ -1,11 +1,15 @@
 use super::{Error, Visit};
-use crate::parser::terms::term_parser::Rule as TermRule;
+use crate::parser::{error::map_int_error, terms::term_parser::Rule as TermRule};
 use pest::iterators::Pair;
 
-pub(super) type Factor = u32;
+pub(in crate::parser) type Factor = u64;
 
 impl Visit<TermRule> for Factor {
     fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
-        pair.as_span().as_str().parse::<Self>().map_err(Error::from)
+        let string = pair.as_span().as_str();
+
+        string
+            .parse::<Self>()
+            .map_err(|error| map_int_error(error, string))
     }
 }
@@ -9,7 +9,7 @@ use crate::parser::{
 };
 use pest::{iterators::Pair, Parser};
 
-pub(super) enum SimpleUnit {
+pub(in crate::parser) enum SimpleUnit {
     Prefixed { prefix: Prefix, atom: Atom },
     Basic { atom: Atom },
     Unity,
@@ -413,4 +413,42 @@ mod tests {
             ]
         }
     }
+
+    #[test]
+    fn validate_simple_unit_with_square_brackets() {
+        parses_to! {
+            parser: TermParser,
+            input: "m[H2O]",
+            rule: Rule::simple_unit,
+            tokens: [simple_unit(0, 6)]
+        }
+
+        parses_to! {
+            parser: TermParser,
+            input: "[in_i'H2O]",
+            rule: Rule::simple_unit,
+            tokens: [simple_unit(0, 10)]
+        }
+
+        parses_to! {
+            parser: TermParser,
+            input: "[m/s2/Hz^(1/2)]",
+            rule: Rule::simple_unit,
+            tokens: [simple_unit(0, 15)]
+        }
+
+        // Without a closing bracket, the "[" is just a symbol character, so
+        // it doesn't swallow the rest of the term.
+        parses_to! {
+            parser: TermParser,
+            input: "[m/s2",
+            rule: Rule::simple_unit,
+            tokens: [simple_unit(0, 2)]
+        }
+
+        assert!(TermParser::parse(Rule::main_term, "m[H2O]").is_ok());
+        assert!(TermParser::parse(Rule::main_term, "[m/s2/Hz^(1/2)]").is_ok());
+        assert!(TermParser::parse(Rule::main_term, "m[H2O").is_err());
+        assert!(TermParser::parse(Rule::main_term, "[m/s2/Hz^(1/2)").is_err());
+    }
 }
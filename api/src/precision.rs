@@ -0,0 +1,714 @@
+//! Significant figures and precision-aware rounding.
+//!
+//! `Precise` pairs a `Measurement` with the `Precision` it was recorded at,
+//! and carries that precision through conversions and arithmetic using the
+//! standard significant figure rules:
+//!
+//! * converting keeps the number of significant figures;
+//! * multiplying and dividing keeps the fewest significant figures;
+//! * adding and subtracting keeps the fewest decimal places.
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use wise_units::{precision::Precise, Convertible};
+//!
+//! let mass = Precise::from_str("1.50 [lb_av]").unwrap();
+//! let converted = mass.convert_to("g").unwrap();
+//!
+//! assert_eq!(converted.to_string(), "680 g");
+//! ```
+//!
+use crate::{Convertible, Error, Measurement, Unit};
+use std::{
+    fmt,
+    ops::{Add, Deref, Div, Mul, Sub},
+    str::FromStr,
+};
+
+/// How precisely a value is known.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// The number of significant figures (ex. 3 for `1.50`).
+    SignificantFigures(u32),
+
+    /// The number of digits after the decimal point (ex. 2 for `1.50`). May
+    /// be negative, for precisions coarser than 1 (ex. -2 for "to the
+    /// nearest hundred").
+    DecimalPlaces(i32),
+}
+
+impl Precision {
+    /// Infers the precision of a number from how it's written: `1.50` has 3
+    /// significant figures, `0.0050` has 2, and `1200` has 2 (trailing zeros
+    /// without a decimal point aren't significant). Zero values (ex. `0.00`)
+    /// have no significant figures, so their precision is inferred as
+    /// `DecimalPlaces` instead.
+    ///
+    /// Returns `None` if `number` isn't a decimal number.
+    ///
+    /// ```rust
+    /// use wise_units::precision::Precision;
+    ///
+    /// assert_eq!(Precision::infer("1.50"), Some(Precision::SignificantFigures(3)));
+    /// assert_eq!(Precision::infer("-1.2e3"), Some(Precision::SignificantFigures(2)));
+    /// assert_eq!(Precision::infer("0.00"), Some(Precision::DecimalPlaces(2)));
+    /// assert_eq!(Precision::infer("one"), None);
+    /// ```
+    ///
+    #[must_use]
+    pub fn infer(number: &str) -> Option<Self> {
+        let unsigned = number.strip_prefix(['+', '-']).unwrap_or(number);
+
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(index) => (
+                &unsigned[..index],
+                unsigned[index + 1..].parse::<i32>().ok()?,
+            ),
+            None => (unsigned, 0),
+        };
+
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let digits = format!("{}{}", integer, fraction);
+        let significant = digits.trim_start_matches('0');
+
+        if significant.is_empty() {
+            let decimal_places = i32::try_from(fraction.len()).ok()?.checked_sub(exponent)?;
+
+            return Some(Self::DecimalPlaces(decimal_places));
+        }
+
+        let significant = if mantissa.contains('.') {
+            significant
+        } else {
+            significant.trim_end_matches('0')
+        };
+
+        Some(Self::SignificantFigures(
+            u32::try_from(significant.len()).ok()?,
+        ))
+    }
+
+    /// The number of significant figures `value` has at this precision. This
+    /// is always at least 1, including for zero and non-finite values.
+    ///
+    #[must_use]
+    pub fn significant_figures(self, value: f64) -> u32 {
+        match self {
+            Self::SignificantFigures(significant_figures) => significant_figures,
+            Self::DecimalPlaces(decimal_places) => {
+                if value == 0.0 || !value.is_finite() {
+                    return 1;
+                }
+
+                let significant_figures = decimal_places
+                    .saturating_add(1)
+                    .saturating_add(magnitude(value));
+
+                u32::try_from(significant_figures.max(1)).unwrap_or(1)
+            }
+        }
+    }
+
+    /// The number of decimal places `value` has at this precision. Zero and
+    /// non-finite values are treated as having a magnitude of 0.
+    ///
+    #[must_use]
+    pub fn decimal_places(self, value: f64) -> i32 {
+        match self {
+            Self::DecimalPlaces(decimal_places) => decimal_places,
+            Self::SignificantFigures(significant_figures) => {
+                let decimal_places = i32::try_from(significant_figures)
+                    .unwrap_or(i32::MAX)
+                    .saturating_sub(1);
+
+                if value == 0.0 || !value.is_finite() {
+                    decimal_places
+                } else {
+                    decimal_places.saturating_sub(magnitude(value))
+                }
+            }
+        }
+    }
+
+    /// Rounds `value` to this precision. Non-finite values are returned
+    /// unchanged.
+    ///
+    #[must_use]
+    pub fn round(self, value: f64) -> f64 {
+        round_to_decimal_places(value, self.decimal_places(value))
+    }
+}
+
+/// The power of 10 of the most significant digit of `value` (ex. 2 for `123`,
+/// -2 for `0.012`).
+///
+#[allow(clippy::cast_possible_truncation)]
+fn magnitude(value: f64) -> i32 {
+    value.abs().log10().floor() as i32
+}
+
+/// Rounds `value` to `decimal_places`. Non-finite values are returned
+/// unchanged, as are values that are already more coarsely rounded than
+/// `decimal_places` can represent.
+///
+pub(crate) fn round_to_decimal_places(value: f64, decimal_places: i32) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+
+    if decimal_places >= 0 {
+        let factor = 10_f64.powi(decimal_places);
+        let scaled = value * factor;
+
+        if scaled.is_finite() {
+            scaled.round() / factor
+        } else {
+            value
+        }
+    } else {
+        let factor = 10_f64.powi(decimal_places.saturating_neg());
+
+        if factor.is_finite() {
+            (value / factor).round() * factor
+        } else {
+            0.0_f64.copysign(value)
+        }
+    }
+}
+
+pub(crate) fn round_to_significant_figures(value: f64, significant_figures: u32) -> f64 {
+    Precision::SignificantFigures(significant_figures).round(value)
+}
+
+/// A `Measurement`, along with the `Precision` its value is known to.
+///
+/// `Precise` can be created from a string like `"1.50 kg"` (where the
+/// precision is inferred from how the value is written) or from a
+/// `Measurement` and an explicit `Precision`. The unrounded value is kept;
+/// `Display` and `rounded()` apply the precision.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Precise {
+    measurement: Measurement,
+    precision: Precision,
+}
+
+impl Precise {
+    #[must_use]
+    pub const fn new(measurement: Measurement, precision: Precision) -> Self {
+        Self {
+            measurement,
+            precision,
+        }
+    }
+
+    #[must_use]
+    pub const fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    #[must_use]
+    pub const fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Measurement {
+        self.measurement
+    }
+
+    /// The `Measurement`, with its value rounded to `self`'s precision.
+    ///
+    #[must_use]
+    pub fn rounded(&self) -> Measurement {
+        Measurement::new(
+            self.precision.round(self.measurement.value()),
+            self.measurement.unit().clone(),
+        )
+    }
+
+    /// Changes the precision to `significant_figures`, rounding the value.
+    ///
+    #[must_use]
+    pub fn round_to_sig_figs(&self, significant_figures: u32) -> Self {
+        Self::new(
+            self.measurement.round_to_sig_figs(significant_figures),
+            Precision::SignificantFigures(significant_figures),
+        )
+    }
+
+    /// Rounds the value to the nearest multiple of `increment` (ex. to the
+    /// nearest `0.5 mg`). The precision becomes that of the increment.
+    ///
+    /// # Errors
+    ///
+    /// See `Measurement::round_to_increment()`.
+    ///
+    pub fn round_to_increment(&self, increment: &Self) -> Result<Self, Error> {
+        let rounded = self
+            .measurement
+            .round_to_increment(&increment.measurement)?;
+        let increment = increment
+            .measurement
+            .difference_in_unit(self.measurement.unit())?;
+        let decimal_places = increment_decimal_places(increment);
+
+        Ok(Self::new(
+            Measurement::new(
+                round_to_decimal_places(rounded.value(), decimal_places),
+                rounded.unit().clone(),
+            ),
+            Precision::DecimalPlaces(decimal_places),
+        ))
+    }
+
+    fn significant_figures(&self) -> u32 {
+        self.precision.significant_figures(self.measurement.value())
+    }
+
+    fn decimal_places(&self) -> i32 {
+        self.precision.decimal_places(self.measurement.value())
+    }
+}
+
+/// The number of decimal places needed to represent `increment` (ex. 1 for
+/// `0.5`, -1 for `50`), or 0 if `increment` is zero or non-finite.
+///
+fn increment_decimal_places(increment: f64) -> i32 {
+    let increment = increment.abs();
+
+    if increment == 0.0 || !increment.is_finite() {
+        return 0;
+    }
+
+    let mut decimal_places = magnitude(increment).saturating_neg();
+
+    while decimal_places < 15
+        && (round_to_decimal_places(increment, decimal_places) - increment).abs() > increment * 1e-9
+    {
+        decimal_places += 1;
+    }
+
+    decimal_places
+}
+
+impl Deref for Precise {
+    type Target = Measurement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.measurement
+    }
+}
+
+impl AsRef<Measurement> for Precise {
+    fn as_ref(&self) -> &Measurement {
+        &self.measurement
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl FromStr
+//-----------------------------------------------------------------------------
+/// Parses a value and a unit expression, separated by whitespace (ex.
+/// `"1.50 kg"`). A value with no unit expression gets the unit `1`.
+///
+impl FromStr for Precise {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (number, expression) = s.split_once(char::is_whitespace).unwrap_or((s, "1"));
+
+        let invalid_number = || Error::InvalidNumber(number.to_string());
+        let precision = Precision::infer(number).ok_or_else(invalid_number)?;
+        let value = f64::from_str(number).map_err(|_| invalid_number())?;
+        let measurement = Measurement::try_new(value, expression.trim())?;
+
+        Ok(Self::new(measurement, precision))
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Display
+//-----------------------------------------------------------------------------
+/// Renders the value rounded to (and padded to) the precision, along with the
+/// unit.
+///
+impl fmt::Display for Precise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimal_places = self.decimal_places();
+        let value = round_to_decimal_places(self.measurement.value(), decimal_places);
+        let decimal_places = usize::try_from(decimal_places).unwrap_or(0);
+
+        write!(
+            f,
+            "{:.*} {}",
+            decimal_places,
+            value,
+            self.measurement.unit()
+        )
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Convertible
+//-----------------------------------------------------------------------------
+/// Converts the `Measurement`, keeping its number of significant figures.
+///
+impl<'a> Convertible<&'a Unit> for Precise {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, other_unit: &'a Unit) -> Result<Self, Self::ConversionError> {
+        let converted = self.measurement.convert_to(other_unit)?;
+
+        // Zero values have no significant figures to keep.
+        let precision = if self.measurement.value() == 0.0 && converted.value() == 0.0 {
+            self.precision
+        } else {
+            Precision::SignificantFigures(self.significant_figures())
+        };
+
+        Ok(Self::new(converted, precision))
+    }
+}
+
+impl<'a> Convertible<&'a str> for Precise {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+        let other_unit = Unit::from_str(expression)?;
+
+        self.convert_to(&other_unit)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Add, Sub
+//-----------------------------------------------------------------------------
+/// The precision of the sum or difference of `lhs` and `rhs`: the fewest
+/// decimal places, once `rhs` is in `lhs`'s unit.
+///
+fn sum_precision(lhs: &Precise, rhs: &Precise) -> Result<Precision, Error> {
+    let converted_rhs = rhs.convert_to(lhs.measurement.unit())?;
+
+    Ok(Precision::DecimalPlaces(
+        lhs.decimal_places().min(converted_rhs.decimal_places()),
+    ))
+}
+
+impl<'a> Add for &'a Precise {
+    type Output = Result<Precise, Error>;
+
+    fn add(self, other: Self) -> Self::Output {
+        let precision = sum_precision(self, other)?;
+        let measurement = (&self.measurement + &other.measurement)?;
+
+        Ok(Precise::new(measurement, precision))
+    }
+}
+
+impl Add for Precise {
+    type Output = Result<Self, Error>;
+
+    fn add(self, other: Self) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<'a> Sub for &'a Precise {
+    type Output = Result<Precise, Error>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let precision = sum_precision(self, other)?;
+        let measurement = (&self.measurement - &other.measurement)?;
+
+        Ok(Precise::new(measurement, precision))
+    }
+}
+
+impl Sub for Precise {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        &self - &other
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Mul, Div
+//-----------------------------------------------------------------------------
+/// The precision of the product or quotient of `lhs` and `rhs`: the fewest
+/// significant figures.
+///
+fn product_precision(lhs: &Precise, rhs: &Precise) -> Precision {
+    Precision::SignificantFigures(lhs.significant_figures().min(rhs.significant_figures()))
+}
+
+impl<'a> Mul for &'a Precise {
+    type Output = Precise;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Precise::new(
+            &self.measurement * &other.measurement,
+            product_precision(self, other),
+        )
+    }
+}
+
+impl Mul for Precise {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+/// Multiplies by an exact number, keeping the number of significant figures.
+///
+impl Mul<f64> for Precise {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self::Output {
+        let precision = Precision::SignificantFigures(self.significant_figures());
+
+        Self::new(self.measurement * other, precision)
+    }
+}
+
+impl<'a> Div for &'a Precise {
+    type Output = Precise;
+
+    fn div(self, other: Self) -> Self::Output {
+        Precise::new(
+            &self.measurement / &other.measurement,
+            product_precision(self, other),
+        )
+    }
+}
+
+impl Div for Precise {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        &self / &other
+    }
+}
+
+/// Divides by an exact number, keeping the number of significant figures.
+///
+impl Div<f64> for Precise {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        let precision = Precision::SignificantFigures(self.significant_figures());
+
+        Self::new(self.measurement / other, precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn precise(s: &str) -> Precise {
+        Precise::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn validate_infer() {
+        let sig_figs = |s| match Precision::infer(s) {
+            Some(Precision::SignificantFigures(n)) => n,
+            other => panic!("unexpected precision for {}: {:?}", s, other),
+        };
+
+        assert_eq!(sig_figs("1"), 1);
+        assert_eq!(sig_figs("1.50"), 3);
+        assert_eq!(sig_figs("0.0050"), 2);
+        assert_eq!(sig_figs("1200"), 2);
+        assert_eq!(sig_figs("1200."), 4);
+        assert_eq!(sig_figs("1.200e3"), 4);
+        assert_eq!(sig_figs(".5"), 1);
+        assert_eq!(sig_figs("-10.0"), 3);
+
+        assert_eq!(Precision::infer("0"), Some(Precision::DecimalPlaces(0)));
+        assert_eq!(Precision::infer("0.000"), Some(Precision::DecimalPlaces(3)));
+        assert_eq!(
+            Precision::infer("0.0e-2"),
+            Some(Precision::DecimalPlaces(3))
+        );
+
+        assert_eq!(Precision::infer(""), None);
+        assert_eq!(Precision::infer("."), None);
+        assert_eq!(Precision::infer("1.2.3"), None);
+        assert_eq!(Precision::infer("1e"), None);
+        assert_eq!(Precision::infer("NaN"), None);
+    }
+
+    // Non-finite values and extreme precisions are returned exactly.
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn validate_precision_extremes() {
+        assert_eq!(
+            Precision::DecimalPlaces(2).significant_figures(f64::INFINITY),
+            1
+        );
+        assert_eq!(Precision::DecimalPlaces(2).significant_figures(f64::NAN), 1);
+        assert_eq!(
+            Precision::DecimalPlaces(i32::MAX).significant_figures(1234.0),
+            i32::MAX.unsigned_abs()
+        );
+        assert_eq!(
+            Precision::SignificantFigures(u32::MAX).decimal_places(0.001),
+            i32::MAX
+        );
+        assert_eq!(
+            Precision::SignificantFigures(3).decimal_places(f64::NEG_INFINITY),
+            2
+        );
+
+        assert_eq!(
+            round_to_significant_figures(f64::INFINITY, 3),
+            f64::INFINITY
+        );
+        assert_eq!(
+            round_to_significant_figures(f64::NEG_INFINITY, 3),
+            f64::NEG_INFINITY
+        );
+        assert!(round_to_significant_figures(f64::NAN, 3).is_nan());
+        assert_eq!(round_to_significant_figures(0.001, u32::MAX), 0.001);
+        assert_eq!(round_to_significant_figures(1e300, 30), 1e300);
+        assert_eq!(Precision::DecimalPlaces(i32::MIN).round(-5.0), -0.0);
+        assert_eq!(increment_decimal_places(f64::INFINITY), 0);
+        assert_eq!(increment_decimal_places(0.5), 1);
+    }
+
+    #[test]
+    fn validate_precision_conversions() {
+        let precision = Precision::SignificantFigures(3);
+        assert_eq!(precision.decimal_places(1.5), 2);
+        assert_eq!(precision.decimal_places(1234.0), -1);
+        assert_eq!(precision.decimal_places(0.012), 4);
+        assert_relative_eq!(precision.round(1234.0), 1230.0);
+        assert_relative_eq!(precision.round(0.012_345), 0.012_3);
+
+        let precision = Precision::DecimalPlaces(1);
+        assert_eq!(precision.significant_figures(12.34), 3);
+        assert_eq!(precision.significant_figures(0.001), 1);
+        assert_relative_eq!(precision.round(12.34), 12.3);
+    }
+
+    #[test]
+    fn validate_from_str() {
+        let subject = precise("1.50 kg");
+        assert_eq!(subject.precision(), Precision::SignificantFigures(3));
+        assert_eq!(
+            subject.measurement(),
+            &Measurement::try_new(1.5, "kg").unwrap()
+        );
+
+        let subject = precise("12");
+        assert_eq!(subject.unit().expression(), "1");
+
+        assert_eq!(
+            Precise::from_str("one kg").unwrap_err(),
+            Error::InvalidNumber("one".to_string())
+        );
+        assert!(Precise::from_str("1.0 foo").is_err());
+    }
+
+    #[test]
+    fn validate_display() {
+        assert_eq!(precise("1.50 kg").to_string(), "1.50 kg");
+        assert_eq!(precise("1200 m").to_string(), "1200 m");
+        assert_eq!(precise("0.00 Cel").to_string(), "0.00 Cel");
+
+        let subject = Precise::new(
+            Measurement::try_new(2.0 / 3.0, "g").unwrap(),
+            Precision::SignificantFigures(2),
+        );
+        assert_eq!(subject.to_string(), "0.67 g");
+
+        let subject = Precise::new(
+            Measurement::try_new(1234.5, "g").unwrap(),
+            Precision::SignificantFigures(2),
+        );
+        assert_eq!(subject.to_string(), "1200 g");
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let converted = precise("1.50 [lb_av]").convert_to("g").unwrap();
+        assert_eq!(converted.precision(), Precision::SignificantFigures(3));
+        assert_eq!(converted.to_string(), "680 g");
+        assert_relative_eq!(converted.value(), 680.388_555);
+
+        let converted = precise("20.0 Cel").convert_to("K").unwrap();
+        assert_eq!(converted.to_string(), "293 K");
+
+        assert!(precise("1 g").convert_to("m").is_err());
+    }
+
+    #[test]
+    fn validate_add_and_sub() {
+        let sum = (precise("1.25 kg") + precise("12 g")).unwrap();
+        assert_eq!(sum.precision(), Precision::DecimalPlaces(2));
+        assert_eq!(sum.to_string(), "1.26 kg");
+
+        let difference = (precise("10.1 m") - precise("2.555 m")).unwrap();
+        assert_eq!(difference.to_string(), "7.5 m");
+
+        assert!((precise("1 m") + precise("1 g")).is_err());
+    }
+
+    #[test]
+    fn validate_mul_and_div() {
+        let product = precise("2.0 m") * precise("3.14159 m");
+        assert_eq!(product.precision(), Precision::SignificantFigures(2));
+        assert_eq!(product.to_string(), "6.3 m2");
+
+        let quotient = precise("10.00 g") / precise("3.0 mL");
+        assert_eq!(quotient.to_string(), "3.3 g/mL");
+
+        let doubled = precise("1.50 g") * 2.0;
+        assert_eq!(doubled.to_string(), "3.00 g");
+
+        let halved = precise("1.50 g") / 2.0;
+        assert_eq!(halved.to_string(), "0.750 g");
+    }
+
+    #[test]
+    fn validate_rounding() {
+        let subject = precise("1.23456 g").round_to_sig_figs(3);
+        assert_eq!(subject.to_string(), "1.23 g");
+        assert_relative_eq!(subject.value(), 1.23);
+
+        let subject = precise("10.37 mg")
+            .round_to_increment(&precise("0.5 mg"))
+            .unwrap();
+        assert_eq!(subject.precision(), Precision::DecimalPlaces(1));
+        assert_eq!(subject.to_string(), "10.5 mg");
+
+        let subject = precise("1234 g")
+            .round_to_increment(&precise("0.05 kg"))
+            .unwrap();
+        assert_eq!(subject.to_string(), "1250 g");
+        assert_eq!(
+            subject.rounded(),
+            Measurement::try_new(1250.0, "g").unwrap()
+        );
+    }
+}
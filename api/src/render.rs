@@ -0,0 +1,697 @@
+//! Renders `Unit`s and `Measurement`s as LaTeX, `MathML` or HTML, for
+//! typesetting in documents and reports.
+//!
+//! Each `Term` is rendered from its print symbols (ex. `μ` for `u`, `°C` for
+//! `Cel`), rather than from the UCUM codes used by `Display`; subscripts,
+//! superscripts and italics in print symbols (ex. `ft<sub>us</sub>` for
+//! `[ft_us]`) are rendered in each format's own markup. `RenderOptions` says
+//! whether negative exponents are rendered as such or as a fraction.
+//!
+//! ```rust
+//! use wise_units::{
+//!     render::{FractionStyle, Render, RenderOptions},
+//!     Measurement, Unit,
+//! };
+//! use std::str::FromStr;
+//!
+//! let unit = Unit::from_str("kg.m2/s2").unwrap();
+//!
+//! assert_eq!(
+//!     unit.to_latex(RenderOptions::default()),
+//!     r"\mathrm{kg}\,\mathrm{m}^{2}\,\mathrm{s}^{-2}"
+//! );
+//! assert_eq!(
+//!     unit.to_html(RenderOptions {
+//!         fraction_style: FractionStyle::Fraction,
+//!         ..RenderOptions::default()
+//!     }),
+//!     "kg&middot;m<sup>2</sup>/s<sup>2</sup>"
+//! );
+//!
+//! let measurement = Measurement::new(9.81, unit);
+//! assert_eq!(
+//!     measurement.to_latex(RenderOptions {
+//!         siunitx: true,
+//!         ..RenderOptions::default()
+//!     }),
+//!     r"\SI{9.81}{\mathrm{kg}\,\mathrm{m}^{2}\,\mathrm{s}^{-2}}"
+//! );
+//! ```
+//!
+use crate::{parser::Term, Measurement, UcumSymbol, Unit};
+use std::fmt::Write;
+
+/// How to render terms with negative exponents.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FractionStyle {
+    /// Keep every term in order, with negative exponents (ex. `m·s⁻¹`).
+    #[default]
+    NegativeExponents,
+
+    /// Move terms with negative exponents to a denominator (ex. `m/s`).
+    Fraction,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    pub fraction_style: FractionStyle,
+
+    /// When rendering a `Measurement` as LaTeX, wrap it in the `siunitx`
+    /// package's `\SI{value}{unit}` command.
+    pub siunitx: bool,
+}
+
+pub trait Render {
+    /// Renders `self` as LaTeX math-mode content (without the surrounding
+    /// `$`s).
+    ///
+    fn to_latex(&self, options: RenderOptions) -> String;
+
+    /// Renders `self` as a `MathML` `<math>` element.
+    ///
+    fn to_mathml(&self, options: RenderOptions) -> String;
+
+    /// Renders `self` as an HTML fragment, using `<sup>` for exponents.
+    ///
+    fn to_html(&self, options: RenderOptions) -> String;
+}
+
+impl Render for Unit {
+    fn to_latex(&self, options: RenderOptions) -> String {
+        render_unit::<Latex>(self, options.fraction_style)
+    }
+
+    fn to_mathml(&self, options: RenderOptions) -> String {
+        format!(
+            "{MATHML_OPEN}{}</math>",
+            render_unit::<MathMl>(self, options.fraction_style)
+        )
+    }
+
+    fn to_html(&self, options: RenderOptions) -> String {
+        render_unit::<Html>(self, options.fraction_style)
+    }
+}
+
+impl Render for Measurement {
+    fn to_latex(&self, options: RenderOptions) -> String {
+        let unit = render_unit::<Latex>(self.unit(), options.fraction_style);
+
+        match (options.siunitx, self.unit().is_unity()) {
+            (true, true) => format!(r"\num{{{}}}", self.value()),
+            (true, false) => format!(r"\SI{{{}}}{{{unit}}}", self.value()),
+            (false, true) => self.value().to_string(),
+            (false, false) => format!(r"{}\,{unit}", self.value()),
+        }
+    }
+
+    fn to_mathml(&self, options: RenderOptions) -> String {
+        let value = format!("<mn>{}</mn>", self.value());
+
+        if self.unit().is_unity() {
+            return format!("{MATHML_OPEN}{value}</math>");
+        }
+
+        format!(
+            "{MATHML_OPEN}{value}<mo>&#x2062;</mo>{}</math>",
+            render_unit::<MathMl>(self.unit(), options.fraction_style)
+        )
+    }
+
+    fn to_html(&self, options: RenderOptions) -> String {
+        if self.unit().is_unity() {
+            return self.value().to_string();
+        }
+
+        format!(
+            "{}&nbsp;{}",
+            self.value(),
+            render_unit::<Html>(self.unit(), options.fraction_style)
+        )
+    }
+}
+
+const MATHML_OPEN: &str = r#"<math xmlns="http://www.w3.org/1998/Math/MathML">"#;
+
+/// The parts of a `Term` that get rendered.
+///
+struct Piece {
+    /// The factor, prefix and atom, using print symbols where they exist.
+    symbol: Option<String>,
+    exponent: i32,
+    annotation: Option<String>,
+}
+
+impl Piece {
+    fn new(term: &Term) -> Option<Self> {
+        let mut symbol = String::new();
+
+        if let Some(factor) = term.factor {
+            if factor != 1 {
+                symbol.push_str(&factor.to_string());
+            }
+        }
+
+        if let Some(atom) = term.atom {
+            if let Some(prefix) = term.prefix {
+                symbol.push_str(
+                    prefix
+                        .print_symbol()
+                        .unwrap_or_else(|| prefix.primary_code()),
+                );
+            }
+
+            symbol.push_str(atom.print_symbol().unwrap_or_else(|| atom.primary_code()));
+        }
+
+        if symbol.is_empty() && term.annotation.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            symbol: (!symbol.is_empty()).then_some(symbol),
+            exponent: term.exponent.unwrap_or(1),
+            annotation: term.annotation.clone(),
+        })
+    }
+}
+
+/// Where a `Run` sits relative to the text before it.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Script {
+    Base,
+    Subscript,
+    Superscript,
+}
+
+/// Part of a symbol that's formatted the same way throughout.
+///
+#[derive(Debug, PartialEq, Eq)]
+struct Run {
+    text: String,
+    italic: bool,
+    script: Script,
+}
+
+/// Splits `symbol` into `Run`s, using the markup that UCUM print symbols
+/// contain: `<sub>`, `<sup>`, `<i>`, and `<r>` for upright text inside `<i>`.
+/// Anything else that looks like a tag is kept as text.
+///
+fn runs(symbol: &str) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    let mut italics = Vec::new();
+    let mut script = Script::Base;
+    let mut rest = symbol;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some((tag, after)) = rest.strip_prefix('<').and_then(|tag| tag.split_once('>')) {
+            let is_markup = match tag {
+                "i" => {
+                    italics.push(true);
+                    true
+                }
+                "r" => {
+                    italics.push(false);
+                    true
+                }
+                "/i" | "/r" => italics.pop().is_some(),
+                "sub" => {
+                    script = Script::Subscript;
+                    true
+                }
+                "sup" => {
+                    script = Script::Superscript;
+                    true
+                }
+                "/sub" | "/sup" => {
+                    script = Script::Base;
+                    true
+                }
+                _ => false,
+            };
+
+            if is_markup {
+                rest = after;
+                continue;
+            }
+        }
+
+        let italic = italics.last().copied().unwrap_or(false);
+
+        match runs.last_mut() {
+            Some(run) if run.italic == italic && run.script == script => run.text.push(c),
+            _ => runs.push(Run {
+                text: c.to_string(),
+                italic,
+                script,
+            }),
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    runs
+}
+
+/// The markup for each output format.
+///
+trait Backend {
+    fn symbol(symbol: &str) -> String;
+    fn annotation(annotation: &str) -> String;
+    fn power(base: String, exponent: i32) -> String;
+    fn product(factors: Vec<String>) -> String;
+    fn fraction(numerator: Option<String>, denominator: String, denominator_len: usize) -> String;
+    fn one() -> String;
+}
+
+fn render_piece<B: Backend>(piece: &Piece, exponent: i32) -> String {
+    let mut rendered = piece
+        .symbol
+        .as_deref()
+        .map(|symbol| {
+            let symbol = B::symbol(symbol);
+
+            if exponent == 1 {
+                symbol
+            } else {
+                B::power(symbol, exponent)
+            }
+        })
+        .unwrap_or_default();
+
+    if let Some(annotation) = &piece.annotation {
+        rendered.push_str(&B::annotation(annotation));
+    }
+
+    rendered
+}
+
+fn render_unit<B: Backend>(unit: &Unit, fraction_style: FractionStyle) -> String {
+    let pieces: Vec<Piece> = unit.terms().iter().filter_map(Piece::new).collect();
+
+    if pieces.is_empty() {
+        return B::one();
+    }
+
+    let product = |pieces: &[&Piece], sign: i32| {
+        B::product(
+            pieces
+                .iter()
+                .map(|piece| render_piece::<B>(piece, sign * piece.exponent))
+                .collect(),
+        )
+    };
+
+    let (numerator, denominator): (Vec<&Piece>, Vec<&Piece>) = match fraction_style {
+        FractionStyle::NegativeExponents => (pieces.iter().collect(), Vec::new()),
+        FractionStyle::Fraction => pieces.iter().partition(|piece| piece.exponent >= 0),
+    };
+
+    if denominator.is_empty() {
+        return product(&numerator, 1);
+    }
+
+    B::fraction(
+        (!numerator.is_empty()).then(|| product(&numerator, 1)),
+        product(&denominator, -1),
+        denominator.len(),
+    )
+}
+
+//-----------------------------------------------------------------------------
+// LaTeX
+//-----------------------------------------------------------------------------
+struct Latex;
+
+impl Latex {
+    fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+
+        for c in text.chars() {
+            match c {
+                '%' | '_' | '#' | '&' | '$' | '{' | '}' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                '\\' => escaped.push_str(r"\backslash{}"),
+                '~' => escaped.push_str(r"\sim{}"),
+                '^' => escaped.push_str(r"\hat{}"),
+                'μ' => escaped.push_str(r"\mu{}"),
+                'π' => escaped.push_str(r"\pi{}"),
+                '°' => escaped.push_str(r"{}^{\circ}"),
+                _ => escaped.push(c),
+            }
+        }
+
+        escaped
+    }
+}
+
+impl Backend for Latex {
+    fn symbol(symbol: &str) -> String {
+        let runs = runs(symbol);
+        let mut rendered = String::new();
+
+        for run in &runs {
+            let text = if run.italic {
+                format!(r"\mathit{{{}}}", Self::escape(&run.text))
+            } else {
+                format!(r"\mathrm{{{}}}", Self::escape(&run.text))
+            };
+
+            let mark = match run.script {
+                Script::Base => {
+                    rendered.push_str(&text);
+                    continue;
+                }
+                Script::Subscript => '_',
+                Script::Superscript => '^',
+            };
+
+            if rendered.is_empty() {
+                rendered.push_str("{}");
+            }
+
+            let _ = write!(rendered, "{mark}{{{text}}}");
+        }
+
+        // Keeps an exponent from being a second superscript.
+        if runs
+            .last()
+            .is_some_and(|run| run.script == Script::Superscript)
+        {
+            rendered = format!("{{{rendered}}}");
+        }
+
+        rendered
+    }
+
+    fn annotation(annotation: &str) -> String {
+        format!(r"\mathrm{{\{{{}\}}}}", Self::escape(annotation))
+    }
+
+    fn power(base: String, exponent: i32) -> String {
+        format!("{base}^{{{exponent}}}")
+    }
+
+    fn product(factors: Vec<String>) -> String {
+        factors.join(r"\,")
+    }
+
+    fn fraction(numerator: Option<String>, denominator: String, _: usize) -> String {
+        format!(
+            r"\frac{{{}}}{{{denominator}}}",
+            numerator.unwrap_or_else(Self::one)
+        )
+    }
+
+    fn one() -> String {
+        "1".to_string()
+    }
+}
+
+//-----------------------------------------------------------------------------
+// MathML
+//-----------------------------------------------------------------------------
+struct MathMl;
+
+impl Backend for MathMl {
+    fn symbol(symbol: &str) -> String {
+        let mut elements: Vec<String> = Vec::new();
+
+        for run in runs(symbol) {
+            let identifier = format!(
+                r#"<mi mathvariant="{}">{}</mi>"#,
+                if run.italic { "italic" } else { "normal" },
+                escape_xml(&run.text)
+            );
+
+            let element = match run.script {
+                Script::Base => identifier,
+                Script::Subscript | Script::Superscript => {
+                    let base = elements
+                        .pop()
+                        .unwrap_or_else(|| "<mrow></mrow>".to_string());
+                    let tag = if run.script == Script::Subscript {
+                        "msub"
+                    } else {
+                        "msup"
+                    };
+
+                    format!("<{tag}>{base}{identifier}</{tag}>")
+                }
+            };
+
+            elements.push(element);
+        }
+
+        if elements.len() == 1 {
+            elements.remove(0)
+        } else {
+            format!("<mrow>{}</mrow>", elements.concat())
+        }
+    }
+
+    fn annotation(annotation: &str) -> String {
+        format!("<mtext>{{{}}}</mtext>", escape_xml(annotation))
+    }
+
+    fn power(base: String, exponent: i32) -> String {
+        let exponent = if exponent < 0 {
+            format!(
+                "<mrow><mo>&#x2212;</mo><mn>{}</mn></mrow>",
+                exponent.unsigned_abs()
+            )
+        } else {
+            format!("<mn>{exponent}</mn>")
+        };
+
+        format!("<msup>{base}{exponent}</msup>")
+    }
+
+    fn product(factors: Vec<String>) -> String {
+        format!("<mrow>{}</mrow>", factors.join("<mo>&#x22C5;</mo>"))
+    }
+
+    fn fraction(numerator: Option<String>, denominator: String, _: usize) -> String {
+        format!(
+            "<mfrac>{}{denominator}</mfrac>",
+            numerator.unwrap_or_else(Self::one)
+        )
+    }
+
+    fn one() -> String {
+        "<mn>1</mn>".to_string()
+    }
+}
+
+//-----------------------------------------------------------------------------
+// HTML
+//-----------------------------------------------------------------------------
+struct Html;
+
+impl Backend for Html {
+    fn symbol(symbol: &str) -> String {
+        runs(symbol)
+            .iter()
+            .map(|run| {
+                let text = escape_xml(&run.text);
+                let text = if run.italic {
+                    format!("<i>{text}</i>")
+                } else {
+                    text
+                };
+
+                match run.script {
+                    Script::Base => text,
+                    Script::Subscript => format!("<sub>{text}</sub>"),
+                    Script::Superscript => format!("<sup>{text}</sup>"),
+                }
+            })
+            .collect()
+    }
+
+    fn annotation(annotation: &str) -> String {
+        format!("{{{}}}", escape_xml(annotation))
+    }
+
+    fn power(base: String, exponent: i32) -> String {
+        if exponent < 0 {
+            format!("{base}<sup>&minus;{}</sup>", exponent.unsigned_abs())
+        } else {
+            format!("{base}<sup>{exponent}</sup>")
+        }
+    }
+
+    fn product(factors: Vec<String>) -> String {
+        factors.join("&middot;")
+    }
+
+    fn fraction(numerator: Option<String>, denominator: String, denominator_len: usize) -> String {
+        let numerator = numerator.unwrap_or_else(Self::one);
+
+        if denominator_len > 1 {
+            format!("{numerator}/({denominator})")
+        } else {
+            format!("{numerator}/{denominator}")
+        }
+    }
+
+    fn one() -> String {
+        "1".to_string()
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const FRACTION: RenderOptions = RenderOptions {
+        fraction_style: FractionStyle::Fraction,
+        siunitx: false,
+    };
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_to_latex() {
+        let options = RenderOptions::default();
+
+        assert_eq!(unit("m").to_latex(options), r"\mathrm{m}");
+        assert_eq!(
+            unit("kg.m2/s2").to_latex(options),
+            r"\mathrm{kg}\,\mathrm{m}^{2}\,\mathrm{s}^{-2}"
+        );
+        assert_eq!(
+            unit("ug/dL").to_latex(options),
+            r"\mathrm{\mu{}g}\,\mathrm{dL}^{-1}"
+        );
+        assert_eq!(unit("Cel").to_latex(options), r"\mathrm{{}^{\circ}C}");
+        assert_eq!(unit("%").to_latex(options), r"\mathrm{\%}");
+        assert_eq!(
+            unit("[ft_us]2").to_latex(options),
+            r"\mathrm{ft}_{\mathrm{us}}^{2}"
+        );
+        assert_eq!(unit("[m_e]").to_latex(options), r"\mathit{m}_{\mathrm{e}}");
+        assert_eq!(
+            unit("gon2").to_latex(options),
+            r"{\mathrm{□}^{\mathrm{g}}}^{2}"
+        );
+        assert_eq!(unit("1").to_latex(options), "1");
+        assert_eq!(
+            unit("mL{total}").to_latex(options),
+            r"\mathrm{mL}\mathrm{\{total\}}"
+        );
+        assert_eq!(
+            unit("10*3/uL").to_latex(options),
+            r"\mathrm{10}^{3}\,\mathrm{\mu{}L}^{-1}"
+        );
+    }
+
+    #[test]
+    fn validate_to_latex_fractions() {
+        assert_eq!(
+            unit("kg.m2/s2").to_latex(FRACTION),
+            r"\frac{\mathrm{kg}\,\mathrm{m}^{2}}{\mathrm{s}^{2}}"
+        );
+        assert_eq!(unit("/s").to_latex(FRACTION), r"\frac{1}{\mathrm{s}}");
+        assert_eq!(unit("m2").to_latex(FRACTION), r"\mathrm{m}^{2}");
+    }
+
+    #[test]
+    fn validate_to_mathml() {
+        assert_eq!(
+            unit("m/s2").to_mathml(RenderOptions::default()),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow><mi \
+             mathvariant=\"normal\">m</mi><mo>&#x22C5;</mo><msup><mi \
+             mathvariant=\"normal\">s</mi><mrow><mo>&#x2212;</mo><mn>2</mn></mrow></msup></mrow>\
+             </math>"
+        );
+        assert_eq!(
+            unit("m/s").to_mathml(FRACTION),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mfrac><mrow><mi \
+             mathvariant=\"normal\">m</mi></mrow><mrow><mi \
+             mathvariant=\"normal\">s</mi></mrow></mfrac></math>"
+        );
+        assert_eq!(
+            unit("[ft_us]").to_mathml(RenderOptions::default()),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow><msub><mi \
+             mathvariant=\"normal\">ft</mi><mi mathvariant=\"normal\">us</mi></msub></mrow></math>"
+        );
+        assert_eq!(
+            unit("[m_e]").to_mathml(RenderOptions::default()),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow><msub><mi \
+             mathvariant=\"italic\">m</mi><mi mathvariant=\"normal\">e</mi></msub></mrow></math>"
+        );
+    }
+
+    #[test]
+    fn validate_to_html() {
+        let options = RenderOptions::default();
+
+        assert_eq!(
+            unit("kg.m2/s2").to_html(options),
+            "kg&middot;m<sup>2</sup>&middot;s<sup>&minus;2</sup>"
+        );
+        assert_eq!(unit("mg/(kg.d)").to_html(FRACTION), "mg/(kg&middot;d)");
+        assert_eq!(unit("/min").to_html(FRACTION), "1/min");
+        assert_eq!(unit("{tablet}").to_html(options), "{tablet}");
+        assert_eq!(unit("[in_i]").to_html(options), "in");
+        assert_eq!(unit("[ft_us]").to_html(options), "ft<sub>us</sub>");
+        assert_eq!(unit("cal_IT").to_html(options), "cal<sub>IT</sub>");
+        assert_eq!(unit("[m_e]").to_html(options), "<i>m</i><sub>e</sub>");
+        assert_eq!(
+            unit("[ft_us]2").to_html(options),
+            "ft<sub>us</sub><sup>2</sup>"
+        );
+    }
+
+    #[test]
+    fn validate_measurements() {
+        let measurement = Measurement::try_new(9.81, "m/s2").unwrap();
+
+        assert_eq!(
+            measurement.to_latex(RenderOptions::default()),
+            r"9.81\,\mathrm{m}\,\mathrm{s}^{-2}"
+        );
+        assert_eq!(
+            measurement.to_latex(RenderOptions {
+                siunitx: true,
+                ..FRACTION
+            }),
+            r"\SI{9.81}{\frac{\mathrm{m}}{\mathrm{s}^{2}}}"
+        );
+        assert_eq!(
+            measurement.to_html(RenderOptions::default()),
+            "9.81&nbsp;m&middot;s<sup>&minus;2</sup>"
+        );
+        assert_eq!(
+            measurement.to_mathml(FRACTION),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mn>9.81</mn><mo>&#x2062;</mo>\
+             <mfrac><mrow><mi mathvariant=\"normal\">m</mi></mrow><mrow><msup><mi \
+             mathvariant=\"normal\">s</mi><mn>2</mn></msup></mrow></mfrac></math>"
+        );
+
+        let measurement = Measurement::try_new(0.5, "1").unwrap();
+        assert_eq!(
+            measurement.to_latex(RenderOptions {
+                siunitx: true,
+                ..RenderOptions::default()
+            }),
+            r"\num{0.5}"
+        );
+        assert_eq!(measurement.to_html(RenderOptions::default()), "0.5");
+    }
+}
@@ -0,0 +1,72 @@
+//! Thread-local settings that are swapped in for the length of a closure (ex.
+//! `with_annotation_policy()`, `with_annotation_rules()`).
+//!
+use std::{cell::RefCell, thread::LocalKey};
+
+/// Runs `f` with `key` set to `value`, then restores the previous value (even
+/// if `f` panics). Calls can be nested; the inner value replaces the outer one
+/// until the inner call returns.
+///
+pub(crate) fn with_value<T, F, R>(key: &'static LocalKey<RefCell<T>>, value: T, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Restore<T: 'static> {
+        key: &'static LocalKey<RefCell<T>>,
+        previous: Option<T>,
+    }
+
+    impl<T> Drop for Restore<T> {
+        fn drop(&mut self) {
+            if let Some(previous) = self.previous.take() {
+                self.key.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+    }
+
+    let previous = key.with(|current| current.replace(value));
+    let _restore = Restore {
+        key,
+        previous: Some(previous),
+    };
+
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static VALUE: RefCell<u8> = const { RefCell::new(0) };
+    }
+
+    fn value() -> u8 {
+        VALUE.with(|value| *value.borrow())
+    }
+
+    #[test]
+    fn validate_with_value() {
+        let result = with_value(&VALUE, 1, || {
+            assert_eq!(value(), 1);
+
+            with_value(&VALUE, 2, || assert_eq!(value(), 2));
+            assert_eq!(value(), 1);
+
+            "meow"
+        });
+
+        assert_eq!(result, "meow");
+        assert_eq!(value(), 0);
+    }
+
+    #[test]
+    fn validate_with_value_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_value(&VALUE, 1, || panic!("meow"));
+        });
+
+        assert!(result.is_err());
+        assert_eq!(value(), 0);
+    }
+}
@@ -0,0 +1,319 @@
+//! Kind-of-quantity aware comparisons, conversions, and arithmetic.
+//!
+//! `Strict` wraps a `Unit` or `Measurement` so that comparisons, conversions,
+//! and arithmetic also check the kind of quantity being measured, not just the
+//! dimension. With it, gray can never be silently treated as sievert:
+//!
+//! ```rust
+//! use wise_units::{Convertible, Error, Measurement, Strict};
+//!
+//! let dose = Strict(Measurement::try_new(2.0, "mGy").unwrap());
+//!
+//! assert!(matches!(
+//!     dose.convert_to("Sv"),
+//!     Err(Error::IncompatibleKinds { .. })
+//! ));
+//! assert!(dose.convert_to("Gy").is_ok());
+//! assert!(dose.convert_to("J/kg").is_ok());
+//! ```
+//!
+use crate::{
+    kind_of_quantity::{kinds_equal, kinds_match},
+    Convertible, Error, IsCompatibleWith, Measurement, Unit,
+};
+use std::{
+    ops::{Add, Deref, Div, Mul, Sub},
+    str::FromStr,
+};
+
+/// A wrapper that adds kind-of-quantity checks to its inner type.
+///
+/// Compatibility checks, conversions, and arithmetic compare kind-of-quantity
+/// (ex. frequency vs. radioactivity) in addition to dimension. Operations
+/// that would mix kinds return an `Error::IncompatibleKinds`.
+///
+#[derive(Clone, Debug)]
+pub struct Strict<T>(pub T);
+
+impl<T> Strict<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Strict<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Strict<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn check_kinds(lhs: &Unit, rhs: &Unit) -> Result<(), Error> {
+    if lhs.is_compatible_with(rhs) && !kinds_match(lhs, rhs) {
+        return Err(Error::IncompatibleKinds {
+            lhs: lhs.expression(),
+            rhs: rhs.expression(),
+        });
+    }
+
+    Ok(())
+}
+
+//-----------------------------------------------------------------------------
+// impl IsCompatibleWith
+//-----------------------------------------------------------------------------
+impl IsCompatibleWith for Strict<Unit> {
+    fn is_compatible_with(&self, rhs: &Self) -> bool {
+        self.0.is_compatible_with(&rhs.0) && kinds_match(&self.0, &rhs.0)
+    }
+}
+
+impl IsCompatibleWith for Strict<Measurement> {
+    fn is_compatible_with(&self, rhs: &Self) -> bool {
+        self.0.is_compatible_with(&rhs.0) && kinds_match(self.0.unit(), rhs.0.unit())
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl PartialEq
+//-----------------------------------------------------------------------------
+/// Equal `Strict` values must measure exactly the same kinds of quantity, so a
+/// generic unit (ex. `J/kg`) isn't equal to a specific one (ex. `Gy`), even
+/// though they're compatible; otherwise `Gy == J/kg` and `J/kg == Sv` would
+/// hold while `Gy != Sv`.
+///
+impl PartialEq for Strict<Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        kinds_equal(&self.0, &other.0) && self.0 == other.0
+    }
+}
+
+impl PartialEq for Strict<Measurement> {
+    fn eq(&self, other: &Self) -> bool {
+        kinds_equal(self.0.unit(), other.0.unit()) && self.0 == other.0
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Convertible
+//-----------------------------------------------------------------------------
+impl<'a> Convertible<&'a Unit> for Strict<Measurement> {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, other_unit: &'a Unit) -> Result<Self, Self::ConversionError> {
+        check_kinds(self.0.unit(), other_unit)?;
+
+        self.0.convert_to(other_unit).map(Strict)
+    }
+}
+
+impl<'a> Convertible<&'a str> for Strict<Measurement> {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+        let other_unit = Unit::from_str(expression)?;
+
+        self.convert_to(&other_unit)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Add, Sub, Mul, Div
+//-----------------------------------------------------------------------------
+impl<'a> Add for &'a Strict<Measurement> {
+    type Output = Result<Strict<Measurement>, Error>;
+
+    fn add(self, other: Self) -> Self::Output {
+        check_kinds(self.0.unit(), other.0.unit())?;
+
+        (&self.0 + &other.0).map(Strict)
+    }
+}
+
+impl Add for Strict<Measurement> {
+    type Output = Result<Self, Error>;
+
+    fn add(self, other: Self) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<'a> Sub for &'a Strict<Measurement> {
+    type Output = Result<Strict<Measurement>, Error>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        check_kinds(self.0.unit(), other.0.unit())?;
+
+        (&self.0 - &other.0).map(Strict)
+    }
+}
+
+impl Sub for Strict<Measurement> {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        &self - &other
+    }
+}
+
+/// Multiplies the `Measurement`s. Unlike multiplying the inner `Measurement`s,
+/// `other` is only converted to `self`'s unit when they measure the same kind
+/// of quantity, so `Gy * Sv` results in `Gy.Sv`, not `Gy2`.
+///
+impl<'a> Mul for &'a Strict<Measurement> {
+    type Output = Strict<Measurement>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        if kinds_match(self.0.unit(), other.0.unit()) {
+            Strict(&self.0 * &other.0)
+        } else {
+            Strict(Measurement::new(
+                self.0.value() * other.0.value(),
+                self.0.unit() * other.0.unit(),
+            ))
+        }
+    }
+}
+
+impl Mul for Strict<Measurement> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+/// Divides the `Measurement`s. Unlike dividing the inner `Measurement`s,
+/// `other` is only converted to `self`'s unit when they measure the same kind
+/// of quantity, so `Sv / Gy` results in `Sv/Gy`, not `1`.
+///
+impl<'a> Div for &'a Strict<Measurement> {
+    type Output = Strict<Measurement>;
+
+    fn div(self, other: Self) -> Self::Output {
+        if kinds_match(self.0.unit(), other.0.unit()) {
+            Strict(&self.0 / &other.0)
+        } else {
+            Strict(Measurement::new(
+                self.0.value() / other.0.value(),
+                self.0.unit() / other.0.unit(),
+            ))
+        }
+    }
+}
+
+impl Div for Strict<Measurement> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        &self / &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn strict(value: f64, expression: &str) -> Strict<Measurement> {
+        Strict(Measurement::try_new(value, expression).unwrap())
+    }
+
+    fn incompatible_kinds(lhs: &str, rhs: &str) -> Error {
+        Error::IncompatibleKinds {
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+
+    #[test]
+    fn validate_is_compatible_with() {
+        assert!(!strict(1.0, "Hz").is_compatible_with(&strict(1.0, "Bq")));
+        assert!(strict(1.0, "Hz").is_compatible_with(&strict(1.0, "s-1")));
+
+        let gy = Strict(Unit::from_str("Gy").unwrap());
+        let sv = Strict(Unit::from_str("Sv").unwrap());
+        assert!(!gy.is_compatible_with(&sv));
+        assert!(gy.0.is_compatible_with(&sv.0));
+    }
+
+    #[test]
+    fn validate_partial_eq() {
+        assert!(strict(1.0, "Hz") != strict(1.0, "Bq"));
+        assert!(strict(1.0, "Hz") == strict(1.0, "Hz"));
+        assert!(strict(1000.0, "mGy") == strict(1.0, "Gy"));
+        assert!(strict(1.0, "s-1") == strict(1.0, "/s"));
+        assert!(Strict(Unit::from_str("Gy").unwrap()) != Strict(Unit::from_str("Sv").unwrap()));
+    }
+
+    #[test]
+    fn validate_partial_eq_is_transitive() {
+        let gy = Strict(Unit::from_str("Gy").unwrap());
+        let generic = Strict(Unit::from_str("J/kg").unwrap());
+        let sv = Strict(Unit::from_str("Sv").unwrap());
+
+        assert!(gy != generic);
+        assert!(generic != sv);
+        assert!(gy != sv);
+        assert!(strict(1.0, "Hz") != strict(1.0, "s-1"));
+
+        // Generic units are still compatible with either kind.
+        assert!(gy.is_compatible_with(&generic));
+        assert!(generic.is_compatible_with(&sv));
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let dose = strict(2.0, "mGy");
+
+        assert_eq!(
+            dose.convert_to("Sv").unwrap_err(),
+            incompatible_kinds("mGy", "Sv")
+        );
+        assert_relative_eq!(dose.convert_to("Gy").unwrap().value(), 0.002);
+
+        // Dimension mismatches are still reported as such.
+        assert!(matches!(
+            dose.convert_to("m").unwrap_err(),
+            Error::IncompatibleUnitTypes { .. }
+        ));
+    }
+
+    #[test]
+    fn validate_add_and_sub() {
+        assert_eq!(
+            (strict(1.0, "Gy") + strict(1.0, "Sv")).unwrap_err(),
+            incompatible_kinds("Gy", "Sv")
+        );
+        assert_eq!(
+            (strict(1.0, "Hz") - strict(1.0, "Bq")).unwrap_err(),
+            incompatible_kinds("Hz", "Bq")
+        );
+
+        let sum = (strict(1.0, "Gy") + strict(500.0, "mGy")).unwrap();
+        assert_relative_eq!(sum.value(), 1.5);
+    }
+
+    #[test]
+    fn validate_mul_and_div() {
+        let product = strict(2.0, "Gy") * strict(3.0, "Sv");
+        assert_eq!(product.unit().expression(), "Gy.Sv");
+        assert_relative_eq!(product.value(), 6.0);
+
+        let quotient = strict(6.0, "Sv") / strict(3.0, "Gy");
+        assert_eq!(quotient.unit().expression(), "Sv/Gy");
+        assert_relative_eq!(quotient.value(), 2.0);
+
+        let product = strict(2.0, "Gy") * strict(3.0, "Gy");
+        assert_eq!(product.unit().expression(), "Gy2");
+    }
+}
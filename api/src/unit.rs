@@ -1,17 +1,22 @@
+mod approx_eq;
 mod as_fraction;
 mod composable;
 mod deref;
 mod display;
 mod field_eq;
+mod from_composition;
+mod from_name;
 mod from_str;
 mod invert;
 mod is_compatible_with;
 mod ops;
 mod partial_eq;
 mod partial_ord;
+mod qudt;
 mod reducible;
 mod term_reducing;
 mod to_reduced;
+mod unece;
 
 #[allow(clippy::module_name_repetitions)]
 mod ucum_unit;
@@ -21,8 +26,12 @@ pub mod custom_ffi;
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use self::qudt::QUDT_UNIT_NAMESPACE;
+
+pub(crate) use self::from_composition::si_unit_from_composition;
+
 use crate::{parser::Term, Error};
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 #[cfg(feature = "cffi")]
 use ffi_common::derive::FFI;
@@ -75,6 +84,17 @@ impl Unit {
         Self { terms }
     }
 
+    /// `self`'s `Term`s as they're spelled, sorted. Two `Unit`s with the same
+    /// sorted terms are made up of the same `Term`s, regardless of order; unlike
+    /// `FieldEq`, atoms with equal scalars (ex. `Hz` and `Bq`) aren't treated as
+    /// the same.
+    ///
+    pub(crate) fn sorted_term_strings(&self) -> Vec<String> {
+        let mut strings: Vec<String> = self.terms.iter().map(ToString::to_string).collect();
+        strings.sort();
+        strings
+    }
+
     /// Creates a new `Unit` that's equivalent to "1".
     ///
     #[must_use]
@@ -160,6 +180,25 @@ impl<'a> TryFrom<&'a str> for Unit {
     }
 }
 
+/// Indexes `mappings` (pairs of codes and the UCUM expressions they're
+/// equivalent to) by the `sorted_term_strings()` of each expression, so codes
+/// can be looked up without parsing every expression again. When more than one
+/// code has the same terms, the first one is kept.
+///
+pub(crate) fn index_by_terms(
+    mappings: &[(&'static str, &'static str)],
+) -> HashMap<Vec<String>, &'static str> {
+    let mut index = HashMap::new();
+
+    for (code, expression) in mappings {
+        if let Ok(unit) = Unit::from_str(expression) {
+            let _ = index.entry(unit.sorted_term_strings()).or_insert(*code);
+        }
+    }
+
+    index
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,81 @@
+use crate::{is_compatible_with::IsCompatibleWith, ucum_unit::UcumUnit, unit::Unit};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+//-----------------------------------------------------------------------------
+// impl AbsDiffEq, RelativeEq, UlpsEq
+//-----------------------------------------------------------------------------
+/// `Unit`s are approximately equal if they're compatible and their `scalar()`
+/// values are approximately equal. Same as `PartialEq`, but with an explicit
+/// tolerance.
+///
+/// ```rust
+/// use approx::relative_eq;
+/// use std::str::FromStr;
+/// use wise_units::Unit;
+///
+/// let unit = Unit::from_str("[in_i]").unwrap();
+/// let other = Unit::from_str("254cm/100").unwrap();
+///
+/// assert!(relative_eq!(unit, other, max_relative = 1e-12));
+/// ```
+///
+impl AbsDiffEq for Unit {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.is_compatible_with(other) && self.scalar().abs_diff_eq(&other.scalar(), epsilon)
+    }
+}
+
+impl RelativeEq for Unit {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.is_compatible_with(other)
+            && self
+                .scalar()
+                .relative_eq(&other.scalar(), epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Unit {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.is_compatible_with(other) && self.scalar().ulps_eq(&other.scalar(), epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use std::str::FromStr;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_approx_eq() {
+        assert_ulps_eq!(unit("km"), unit("1000m"));
+        assert_relative_eq!(unit("[ft_i]"), unit("12[in_i]"), max_relative = 1e-12);
+        assert!(unit("g").abs_diff_eq(&unit("1001mg"), 0.01));
+        assert!(!unit("g").abs_diff_eq(&unit("m"), 1.0));
+        assert!(!unit("g").relative_eq(&unit("m"), 1.0, 1.0));
+        assert!(!unit("g").ulps_eq(&unit("m"), 1.0, 4));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    parser::{composition, Atom, Composition, Dimension, Prefix, Term},
+    unit::Unit,
+};
+
+/// The UCUM base atom for each `Dimension`, in the order they're rendered.
+///
+const BASE_ATOMS: [(Dimension, Atom); 7] = [
+    (Dimension::Length, Atom::Meter),
+    (Dimension::Mass, Atom::Gram),
+    (Dimension::Time, Atom::Second),
+    (Dimension::PlaneAngle, Atom::Radian),
+    (Dimension::Temperature, Atom::Kelvin),
+    (Dimension::ElectricCharge, Atom::Coulomb),
+    (Dimension::LuminousIntensity, Atom::Candela),
+];
+
+/// Named SI derived units, by their `Composition`. Units whose dimension is
+/// shared by more than one kind of quantity (ex. `Hz` and `Bq`, `Gy` and `Sv`)
+/// are deliberately left out, since there's no way to pick the right one.
+///
+const DERIVED_ATOMS: [(Composition, Atom); 14] = [
+    (composition::FORCE, Atom::Newton),
+    (composition::PRESSURE, Atom::Pascal),
+    (composition::ENEGERY, Atom::Joule),
+    (composition::POWER, Atom::Watt),
+    // Q.T-1
+    (
+        Composition::new_any(Some(1), None, None, None, None, None, Some(-1)),
+        Atom::Ampere,
+    ),
+    // L2.M.Q-1.T-2
+    (
+        Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        Atom::Volt,
+    ),
+    // L-2.M-1.Q2.T2
+    (
+        Composition::new_any(Some(2), Some(-2), None, Some(-1), None, None, Some(2)),
+        Atom::Farad,
+    ),
+    // L2.M.Q-2.T-1
+    (
+        Composition::new_any(Some(-2), Some(2), None, Some(1), None, None, Some(-1)),
+        Atom::Ohm,
+    ),
+    // L-2.M-1.Q2.T
+    (
+        Composition::new_any(Some(2), Some(-2), None, Some(-1), None, None, Some(1)),
+        Atom::Siemens,
+    ),
+    // L2.M.Q-1.T-1
+    (
+        Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-1)),
+        Atom::Weber,
+    ),
+    // M.Q-1.T-1
+    (
+        Composition::new_any(Some(-1), None, None, Some(1), None, None, Some(-1)),
+        Atom::Tesla,
+    ),
+    // L2.M.Q-2
+    (
+        Composition::new_any(Some(-2), Some(2), None, Some(1), None, None, None),
+        Atom::Henry,
+    ),
+    // F.A2
+    (
+        Composition::new_any(None, None, Some(1), None, Some(2), None, None),
+        Atom::Lumen,
+    ),
+    // L-2.F.A2
+    (
+        Composition::new_any(None, Some(-2), Some(1), None, Some(2), None, None),
+        Atom::Lux,
+    ),
+];
+
+impl Unit {
+    /// Builds the canonical UCUM base-unit expression for `composition`,
+    /// using the base atoms `m`, `g`, `s`, `rad`, `K`, `C` and `cd`. A
+    /// dimensionless `composition` results in a unity `Unit` (`"1"`).
+    ///
+    /// ```rust
+    /// use wise_units::{composition, Composition, Unit};
+    ///
+    /// assert_eq!(Unit::from_composition(composition::PRESSURE).expression(), "g/m.s2");
+    /// assert_eq!(Unit::from_composition(Composition::default()).expression(), "1");
+    /// ```
+    ///
+    #[must_use]
+    pub fn from_composition(composition: Composition) -> Self {
+        units_from_composition(composition, false)
+    }
+}
+
+/// Builds the SI-normalized `Unit` for `composition`: the same as
+/// `Unit::from_composition()`, but with `kg` instead of `g`. If
+/// `use_derived_units` is set and `composition` matches one of the named SI
+/// derived units (ex. `N`, `Pa`, `J`), that unit is used instead.
+///
+pub(crate) fn si_unit_from_composition(composition: Composition, use_derived_units: bool) -> Unit {
+    if use_derived_units {
+        if let Some((_, atom)) = DERIVED_ATOMS.iter().find(|(c, _)| *c == composition) {
+            return Unit::new(vec![Term::new(None, Some(*atom))]);
+        }
+    }
+
+    units_from_composition(composition, true)
+}
+
+fn units_from_composition(composition: Composition, use_kilogram: bool) -> Unit {
+    let terms: Vec<Term> = BASE_ATOMS
+        .iter()
+        .filter_map(|(dimension, atom)| {
+            let exponent = composition.exponent(*dimension)?;
+            let prefix = (use_kilogram && *atom == Atom::Gram).then_some(Prefix::Kilo);
+
+            let mut term = Term::new(prefix, Some(*atom));
+            term.exponent = (exponent != 1).then_some(exponent);
+
+            Some(term)
+        })
+        .collect();
+
+    if terms.is_empty() {
+        Unit::new_unity()
+    } else {
+        Unit::new(terms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Composable;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_from_composition() {
+        let expressions = [
+            (composition::DIMLESS, "1"),
+            (composition::LENGTH, "m"),
+            (composition::VOLUME, "m3"),
+            (composition::VELOCITY, "m/s"),
+            (composition::FORCE, "m.g/s2"),
+            (composition::SPECIFIC_HEAT, "m2/s2.K"),
+            (composition::ELECTRIC_CHARGE, "C"),
+            (Composition::new_luminous_intensity(1), "cd"),
+            (Composition::new_plane_angle(-1), "/rad"),
+        ];
+
+        for (composition, expected) in expressions {
+            let unit = Unit::from_composition(composition);
+            assert_eq!(unit.expression(), expected);
+            assert_eq!(unit.composition(), composition);
+        }
+    }
+
+    #[test]
+    fn validate_si_unit_from_composition() {
+        assert_eq!(
+            si_unit_from_composition(composition::FORCE, false).expression(),
+            "m.kg/s2"
+        );
+        assert_eq!(
+            si_unit_from_composition(composition::FORCE, true).expression(),
+            "N"
+        );
+        assert_eq!(
+            si_unit_from_composition(composition::DENSITY, true).expression(),
+            "kg/m3"
+        );
+        assert_eq!(
+            si_unit_from_composition(Composition::new_time(-1), true).expression(),
+            "/s"
+        );
+    }
+
+    #[test]
+    fn validate_derived_atom_compositions() {
+        for (composition, atom) in DERIVED_ATOMS {
+            let unit = Unit::from_str(&atom.to_string()).unwrap();
+            assert_eq!(unit.composition(), composition, "{}", atom);
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use crate::{
+    parser::{
+        Atom, Classification, Composable, Composition, Dimension, Error as ParserError, Prefix,
+        Term,
+    },
+    ucum_unit::UcumUnit,
+    unit::Unit,
+    Error, UcumSymbol,
+};
+use approx::ulps_eq;
+use std::collections::HashMap;
+
+/// A way of reading a phrase, with a score for ranking it against other
+/// readings; lower is better.
+///
+/// The reading's `Composition` and scalar are kept alongside its `Term`s, to
+/// tell readings of the same unit apart without working them out again.
+///
+#[derive(Clone)]
+struct Reading {
+    terms: Vec<Term>,
+    composition: Composition,
+    scalar: f64,
+    score: u32,
+}
+
+/// The `Atom`s (and `Prefix`es) whose name starts at some word, along with
+/// where the name ends.
+///
+type Matches = Vec<(Option<Prefix>, Atom, usize)>;
+
+/// Reads the words of a phrase. Each word's `Atom` matches and each suffix's
+/// readings are only worked out once, and a suffix only keeps one reading
+/// per unit, so this doesn't take exponentially longer for longer phrases.
+///
+struct Reader<'a> {
+    words: &'a [String],
+    matches: Vec<Matches>,
+    readings: HashMap<(usize, i32), Vec<Reading>>,
+}
+
+impl Unit {
+    /// Parses a unit from its natural-language name (ex. "kilometers per
+    /// hour", "square feet", "milligrams per deciliter", "degrees Celsius"),
+    /// using the names from `Atom::names()` and `Prefix::names()`. Plurals,
+    /// "per", "square"/"cubic" and "squared"/"cubed" are understood.
+    ///
+    /// When the phrase could mean more than one unit (ex. "pound" is the
+    /// avoirdupois and the troy pound), this returns the most
+    /// likely one; use `Unit::candidates_from_name()` to get all of them.
+    ///
+    /// ```rust
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_name("kilometers per hour").unwrap();
+    /// assert_eq!(unit.expression(), "km/h");
+    ///
+    /// let unit = Unit::from_name("cubic centimeter").unwrap();
+    /// assert_eq!(unit.expression(), "cm3");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::ParsingFailed` if no unit has the name `phrase`.
+    ///
+    pub fn from_name(phrase: &str) -> Result<Self, Error> {
+        Self::candidates_from_name(phrase)
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParsingFailed(ParserError::UnknownUnitString(phrase.to_string())))
+    }
+
+    /// All of the units that `phrase` could name, most likely first. Units
+    /// are ranked by how common their classification is (SI first, then
+    /// ISO 1000, international customary, US and British units, and so on);
+    /// when names are shared between an angle and something else (ex.
+    /// "minute"), the angle comes last, and `L` comes before `l`. Equal units
+    /// (ex. `[ft_i]2` and `[sft_i]` for "square feet") are only listed once.
+    ///
+    /// ```rust
+    /// use wise_units::Unit;
+    ///
+    /// let candidates: Vec<String> = Unit::candidates_from_name("pounds")
+    ///     .iter()
+    ///     .map(Unit::expression)
+    ///     .collect();
+    ///
+    /// assert_eq!(candidates, ["[lb_av]", "[lb_tr]"]);
+    /// ```
+    ///
+    #[must_use]
+    pub fn candidates_from_name(phrase: &str) -> Vec<Self> {
+        let words: Vec<String> = phrase
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .filter(|word| !word.is_empty())
+            .map(fold)
+            .collect();
+
+        if words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut readings = Reader::new(&words).read(0, 1);
+        readings.sort_by_key(|reading| reading.score);
+
+        readings
+            .into_iter()
+            .map(|reading| Self::new(reading.terms))
+            .collect()
+    }
+}
+
+/// Lowercases `text` and strips accents, so "Ampère" matches "ampere".
+///
+fn fold(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ä' | 'å' => 'a',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ò' | 'ó' | 'ô' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            c => c,
+        })
+        .collect()
+}
+
+/// Whether `word` (from the phrase) is `name_word` (from an `Atom`'s name),
+/// allowing for plurals and British spellings.
+///
+fn word_matches(word: &str, name_word: &str) -> bool {
+    let word = word.replace("metre", "meter").replace("litre", "liter");
+
+    word == name_word
+        || word.strip_suffix('s') == Some(name_word)
+        || word.strip_suffix("es") == Some(name_word)
+        || word
+            .strip_suffix("ies")
+            .is_some_and(|stem| name_word.strip_suffix('y') == Some(stem))
+        || word == "feet" && name_word == "foot"
+}
+
+/// How common an `Atom`'s classification is, for ranking readings.
+///
+const fn classification_rank(classification: Classification) -> u32 {
+    match classification {
+        Classification::Si => 0,
+        Classification::Iso1000 => 1,
+        Classification::Intcust => 2,
+        Classification::Avoirdupois => 3,
+        Classification::UsLengths | Classification::UsVolumes => 4,
+        Classification::BritLength | Classification::BritVolumes => 5,
+        Classification::Cgs | Classification::Heat | Classification::Infotech => 6,
+        Classification::Chemical
+        | Classification::Clinical
+        | Classification::Dimless
+        | Classification::Levels
+        | Classification::Misc => 7,
+        Classification::Const | Classification::Typeset | Classification::Troy => 8,
+        Classification::Apoth => 9,
+    }
+}
+
+/// Ranks `atom` by its classification; angles come after other atoms with the
+/// same classification, and `l` comes after `L`, since it's easily mistaken
+/// for `1`.
+///
+fn atom_score(atom: Atom) -> u32 {
+    let composition = atom.composition();
+    let is_angle = composition
+        .exponent(Dimension::PlaneAngle)
+        .is_some_and(|exponent| composition == Composition::new_plane_angle(exponent));
+
+    classification_rank(atom.classification()) * 4
+        + u32::from(is_angle) * 2
+        + u32::from(matches!(atom, Atom::Liter))
+}
+
+impl<'a> Reader<'a> {
+    fn new(words: &'a [String]) -> Self {
+        Self {
+            words,
+            matches: (0..words.len())
+                .map(|start| match_atoms(words, start))
+                .collect(),
+            readings: HashMap::new(),
+        }
+    }
+
+    /// Every way that `words[start..]` can be read as a list of `Term`s,
+    /// where `sign` is `-1` after a "per". Readings of the same unit (ex.
+    /// "foot" as `[ft_i]` or `[ft_us]`, in either order) are only kept once,
+    /// with the best score.
+    ///
+    fn read(&mut self, start: usize, sign: i32) -> Vec<Reading> {
+        if let Some(readings) = self.readings.get(&(start, sign)) {
+            return readings.clone();
+        }
+
+        let readings = self.read_uncached(start, sign);
+        let _ = self.readings.insert((start, sign), readings.clone());
+
+        readings
+    }
+
+    fn read_uncached(&mut self, start: usize, sign: i32) -> Vec<Reading> {
+        let words = self.words;
+
+        if start == words.len() {
+            return vec![Reading {
+                terms: Vec::new(),
+                composition: Composition::default(),
+                scalar: 1.0,
+                score: 0,
+            }];
+        }
+
+        let mut readings = Vec::new();
+
+        // "per" has to be followed by a unit, not by another "per".
+        if words[start] == "per" && words.get(start + 1).is_some_and(|word| word != "per") {
+            for reading in self.read(start + 1, -1) {
+                add_reading(&mut readings, reading);
+            }
+        }
+
+        let (power, start) = match words[start].as_str() {
+            "square" => (2, start + 1),
+            "cubic" => (3, start + 1),
+            _ => (1, start),
+        };
+
+        let matches = self.matches.get(start).cloned().unwrap_or_default();
+
+        for (prefix, atom, end) in matches {
+            let (exponent, end) = match words.get(end).map(String::as_str) {
+                Some("squared") if power == 1 => (2, end + 1),
+                Some("cubed") if power == 1 => (3, end + 1),
+                _ => (power, end),
+            };
+
+            let exponent = sign * exponent;
+            let term = Term {
+                factor: None,
+                prefix,
+                atom: Some(atom),
+                exponent: (exponent != 1).then_some(exponent),
+                annotation: None,
+            };
+
+            let composition = term.composition();
+            let scalar = term.scalar();
+
+            for rest in self.read(end, sign) {
+                let mut terms = vec![term.clone()];
+                terms.extend(rest.terms);
+
+                let reading = Reading {
+                    terms,
+                    composition: composition * rest.composition,
+                    scalar: scalar * rest.scalar,
+                    score: atom_score(atom) + rest.score + 1,
+                };
+
+                add_reading(&mut readings, reading);
+            }
+        }
+
+        readings
+    }
+}
+
+/// Adds `reading` to `readings`, unless there's already a reading of the
+/// same unit; of the two, the one with the better score is kept.
+///
+fn add_reading(readings: &mut Vec<Reading>, reading: Reading) {
+    match readings.iter_mut().find(|existing| {
+        existing.composition == reading.composition && ulps_eq!(existing.scalar, reading.scalar)
+    }) {
+        Some(existing) if reading.score < existing.score => *existing = reading,
+        Some(_) => (),
+        None => readings.push(reading),
+    }
+}
+
+/// The `Atom`s (and `Prefix`es) whose name starts at `words[start]`, along
+/// with where the name ends.
+///
+fn match_atoms(words: &[String], start: usize) -> Matches {
+    let mut matches = Vec::new();
+
+    let Some(first_word) = words.get(start) else {
+        return matches;
+    };
+
+    for atom in Atom::iter() {
+        for name in atom.names() {
+            let name_words: Vec<String> = name.split([' ', '-']).map(fold).collect();
+            let end = start + name_words.len();
+
+            if end > words.len()
+                || !words[start + 1..end]
+                    .iter()
+                    .zip(&name_words[1..])
+                    .all(|(word, name_word)| word_matches(word, name_word))
+            {
+                continue;
+            }
+
+            if word_matches(first_word, &name_words[0]) {
+                matches.push((None, atom, end));
+            } else if atom.is_metric() {
+                let prefix = Prefix::iter().find(|prefix| {
+                    prefix
+                        .names()
+                        .iter()
+                        .any(|prefix_name| is_prefixed(first_word, prefix_name, &name_words[0]))
+                });
+
+                if let Some(prefix) = prefix {
+                    matches.push((Some(prefix), atom, end));
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// Whether `word` is `prefix_name` followed by `name_word`. Like in "hectare"
+/// and "megohm", a prefix's final vowel may be dropped before a vowel.
+///
+fn is_prefixed(word: &str, prefix_name: &str, name_word: &str) -> bool {
+    if word
+        .strip_prefix(prefix_name)
+        .is_some_and(|rest| word_matches(rest, name_word))
+    {
+        return true;
+    }
+
+    let elided = &prefix_name[..prefix_name.len() - 1];
+
+    prefix_name.ends_with(['a', 'e', 'i', 'o', 'u'])
+        && name_word.starts_with(['a', 'e', 'i', 'o', 'u'])
+        && word
+            .strip_prefix(elided)
+            .is_some_and(|rest| word_matches(rest, name_word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn expression(phrase: &str) -> String {
+        Unit::from_name(phrase).unwrap().expression()
+    }
+
+    #[test]
+    fn validate_from_name() {
+        assert_eq!(expression("meter"), "m");
+        assert_eq!(expression("meters"), "m");
+        assert_eq!(expression("Metres"), "m");
+        assert_eq!(expression("kilometers per hour"), "km/h");
+        assert_eq!(expression("milligrams per deciliter"), "mg/dL");
+        assert_eq!(expression("cubic centimeter"), "cm3");
+        assert_eq!(expression("square feet"), "[ft_i]2");
+        assert_eq!(expression("degrees Celsius"), "Cel");
+        assert_eq!(expression("degree fahrenheit"), "[degF]");
+        assert_eq!(expression("meters per second squared"), "m/s2");
+        assert_eq!(expression("newton meter"), "N.m");
+        assert_eq!(expression("kilowatt hours"), "kW.h");
+        assert_eq!(expression("per minute"), "/min");
+        assert_eq!(expression("hectares"), "har");
+        assert_eq!(expression("megohms"), "MOhm");
+        assert_eq!(expression("milliamperes"), "mA");
+        assert_eq!(expression("parts per million"), "[ppm]");
+        assert_eq!(expression("pound-force"), "[lbf_av]");
+        assert_eq!(expression("microliters"), "uL");
+        assert_eq!(expression("liters"), "L");
+    }
+
+    #[test]
+    fn validate_from_name_per_is_left_to_right() {
+        assert_eq!(
+            Unit::from_name("milligrams per kilogram per day").unwrap(),
+            Unit::from_str("mg/(kg.d)").unwrap()
+        );
+    }
+
+    #[test]
+    fn validate_candidates_from_name() {
+        let candidates = Unit::candidates_from_name("feet");
+        assert_eq!(candidates[0].expression(), "[ft_i]");
+        assert_eq!(candidates.len(), 3);
+
+        let candidates = Unit::candidates_from_name("minutes");
+        assert_eq!(candidates[0].expression(), "min");
+        assert_eq!(candidates[1].expression(), "'");
+
+        assert_eq!(Unit::candidates_from_name("liter").len(), 1);
+        assert!(Unit::candidates_from_name("").is_empty());
+    }
+
+    /// Each suffix of the phrase is only read once per sign.
+    ///
+    #[test]
+    fn validate_from_name_long_phrase() {
+        for (phrase, expected) in [
+            (["square feet per second"; 4].join(" per "), "[ft_i]-4.s-4"),
+            (["feet"; 8].join(" "), "[ft_i]8"),
+        ] {
+            let words: Vec<String> = phrase.split(' ').map(fold).collect();
+            let mut reader = Reader::new(&words);
+
+            assert!(!reader.read(0, 1).is_empty());
+            assert!(reader.readings.len() <= 2 * (words.len() + 1));
+            assert_eq!(
+                Unit::from_name(&phrase).unwrap(),
+                Unit::from_str(expected).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn validate_from_name_errors() {
+        assert!(Unit::from_name("furlongs per fortnight").is_err());
+        assert!(Unit::from_name("meters per").is_err());
+        assert!(Unit::from_name("per per second").is_err());
+        assert!(Unit::from_name("meters per per second").is_err());
+        assert!(Unit::from_name("square").is_err());
+        assert!(Unit::from_name("kilo feet").is_err());
+        assert!(Unit::from_name("kilofeet").is_err());
+    }
+}
@@ -16,6 +16,7 @@ impl FromStr for Unit {
 #[cfg(test)]
 mod tests {
     use super::{FromStr, Unit};
+    use crate::Error;
 
     #[test]
     fn validate_from_str_error() {
@@ -23,6 +24,46 @@ mod tests {
         assert!(unit.is_err());
     }
 
+    #[test]
+    fn validate_digits_in_square_brackets() {
+        let unit = Unit::from_str("m[H2O]").unwrap();
+        assert_eq!(unit.expression(), "m[H2O]");
+
+        let unit = Unit::from_str("[in_i'H2O]2").unwrap();
+        assert_eq!(unit.expression(), "[in_i'H2O]2");
+
+        let unit = Unit::from_str("[m/s2/Hz^(1/2)]").unwrap();
+        assert_eq!(unit.expression(), "[m/s2/Hz^(1/2)]");
+    }
+
+    #[test]
+    fn validate_large_factors() {
+        let unit = Unit::from_str("100000000000m").unwrap();
+        assert_eq!(unit.expression(), "100000000000m");
+        assert_eq!(unit.terms[0].factor, Some(100_000_000_000));
+    }
+
+    #[test]
+    fn validate_overflow() {
+        let overflow = |term: &str| Error::Overflow {
+            term: term.to_string(),
+        };
+
+        assert_eq!(
+            Unit::from_str("99999999999999999999m").unwrap_err(),
+            overflow("99999999999999999999")
+        );
+        assert_eq!(
+            Unit::from_str("m99999999999").unwrap_err(),
+            overflow("99999999999")
+        );
+        assert_eq!(
+            Unit::from_str("ar1073741824").unwrap_err(),
+            overflow("ar1073741824")
+        );
+        assert!(Unit::from_str("m2147483647").is_ok());
+    }
+
     #[test]
     fn validate_annotation() {
         let unit = Unit::from_str("{foo}").unwrap();
@@ -31,4 +72,13 @@ mod tests {
 
         assert_eq!(annotation, &Some("foo".to_string()));
     }
+
+    #[test]
+    fn validate_unbalanced_square_brackets() {
+        assert!(Unit::from_str("m[H2O").is_err());
+        assert!(Unit::from_str("mH2O]").is_err());
+        assert!(Unit::from_str("[m/s2/Hz^(1/2)").is_err());
+        assert!(Unit::from_str("m/s2/Hz^(1/2)]").is_err());
+        assert!(Unit::from_str("[in_i'H2O").is_err());
+    }
 }
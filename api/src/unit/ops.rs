@@ -1,24 +1,61 @@
 use super::term_reducing;
-use crate::{invert::ToInverse, Term, Unit};
+use crate::{invert::ToInverse, parser::validate_composition, Error, Term, Unit};
 use std::ops::{Div, Mul};
 
+impl Unit {
+    /// Same as `self * other`, but returns an `Error::Overflow` instead of
+    /// panicking if combining the `Term`s' exponents overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Overflow` if any resulting exponent doesn't fit in an
+    /// `i32`.
+    ///
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        try_multiply_terms(&self.terms, &other.terms).map(Self::new)
+    }
+
+    /// Same as `self / other`, but returns an `Error::Overflow` instead of
+    /// panicking if combining the `Term`s' exponents overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Overflow` if any resulting exponent doesn't fit in an
+    /// `i32`.
+    ///
+    pub fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        try_divide_terms(&self.terms, &other.terms).map(Self::new)
+    }
+}
+
 //-----------------------------------------------------------------------------
 // impl Div
 //-----------------------------------------------------------------------------
-fn divide_terms(lhs: &[Term], rhs: &[Term]) -> Vec<Term> {
+fn try_divide_terms(lhs: &[Term], rhs: &[Term]) -> Result<Vec<Term>, Error> {
     let mut terms = Vec::with_capacity(lhs.len() + rhs.len());
     terms.extend_from_slice(lhs);
 
     for term in rhs.iter() {
+        // i32::MIN can't be negated.
+        if term.exponent == Some(i32::MIN) {
+            return Err(Error::Overflow {
+                term: term.to_string(),
+            });
+        }
+
         terms.push(term.to_inverse());
     }
 
-    term_reducing::reduce_terms(&terms)
+    let terms = term_reducing::try_reduce_terms(&terms)?;
+    validate_composition(&terms)?;
+
+    Ok(terms)
 }
 
 #[cfg_attr(feature = "cffi", ffi_common::derive::expose_fn(extend_type(Unit)))]
 fn divide_units(lhs: &Unit, rhs: &Unit) -> Unit {
-    Unit::new(divide_terms(&*lhs.terms, &*rhs.terms))
+    lhs.checked_div(rhs)
+        .unwrap_or_else(|error| panic!("Unable to divide units: {}", error))
 }
 
 impl Div for Unit {
@@ -61,18 +98,22 @@ impl<'a> Div<Unit> for &'a Unit {
 // impl Mul
 //-----------------------------------------------------------------------------
 
-fn multiply_terms(lhs: &[Term], rhs: &[Term]) -> Vec<Term> {
+fn try_multiply_terms(lhs: &[Term], rhs: &[Term]) -> Result<Vec<Term>, Error> {
     let mut terms = Vec::with_capacity(lhs.len() + rhs.len());
 
     terms.extend_from_slice(lhs);
     terms.extend_from_slice(rhs);
 
-    term_reducing::reduce_terms(&terms)
+    let terms = term_reducing::try_reduce_terms(&terms)?;
+    validate_composition(&terms)?;
+
+    Ok(terms)
 }
 
 #[cfg_attr(feature = "cffi", ffi_common::derive::expose_fn(extend_type(Unit)))]
 fn multiply_units(lhs: &Unit, rhs: &Unit) -> Unit {
-    Unit::new(multiply_terms(&*lhs.terms, &*rhs.terms))
+    lhs.checked_mul(rhs)
+        .unwrap_or_else(|error| panic!("Unable to multiply units: {}", error))
 }
 
 impl Mul for Unit {
@@ -156,4 +197,43 @@ mod tests {
         let seed_per_acre = Unit::from_str("{seed}/[acr_us]").unwrap();
         assert_eq!(seed_per_acre * &*ACRE, *SEED);
     }
+
+    #[test]
+    fn validate_mul_keeps_atoms_with_equal_scalars() {
+        let gray = Unit::from_str("Gy").unwrap();
+        let sievert = Unit::from_str("Sv").unwrap();
+        assert_eq!((&gray * &sievert).expression(), "Gy.Sv");
+        assert_eq!((&gray / &sievert).expression(), "Gy/Sv");
+    }
+
+    #[test]
+    fn validate_checked_mul_and_div() {
+        let big = Unit::from_str("m2147483647").unwrap();
+        let overflow = Error::Overflow {
+            term: "m".to_string(),
+        };
+
+        assert_eq!(big.checked_mul(&METER).unwrap_err(), overflow);
+        assert_eq!(
+            big.checked_div(&Unit::from_str("m-1").unwrap())
+                .unwrap_err(),
+            overflow
+        );
+        assert_eq!(
+            METER.checked_div(&METER).unwrap(),
+            Unit::from_str("1").unwrap()
+        );
+        assert_eq!(METER.checked_mul(&METER).unwrap().expression(), "m2");
+
+        // The exponents fit in an i32, but the dimension (L2 per are) doesn't.
+        let are = Unit::from_str("ar1073741823").unwrap();
+        assert!(are.checked_mul(&Unit::from_str("ar").unwrap()).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Integer overflow")]
+    fn validate_mul_overflow_panics() {
+        let big = Unit::from_str("m2147483647").unwrap();
+        let _ = big * &*METER;
+    }
 }
@@ -0,0 +1,325 @@
+//! Mappings between UCUM unit expressions and QUDT unit vocabulary
+//! identifiers (ex. `http://qudt.org/vocab/unit/KiloGM`).
+//!
+use super::{index_by_terms, Unit};
+use crate::Error;
+use std::{collections::HashMap, str::FromStr, sync::OnceLock};
+
+const CODE_SYSTEM: &str = "QUDT";
+
+/// Namespace of the QUDT unit vocabulary.
+///
+pub const QUDT_UNIT_NAMESPACE: &str = "http://qudt.org/vocab/unit/";
+
+const CURIE_PREFIX: &str = "unit:";
+
+/// Pairs of QUDT unit local names and the UCUM expression they're equivalent
+/// to. A local name may be listed more than once (to account for alternate
+/// UCUM spellings); the first entry is used for `Unit::from_qudt()`.
+///
+const MAPPINGS: &[(&str, &str)] = &[
+    // Dimensionless
+    ("UNITLESS", "1"),
+    ("PERCENT", "%"),
+    ("PPM", "[ppm]"),
+    ("PPB", "[ppb]"),
+    // Length
+    ("M", "m"),
+    ("KiloM", "km"),
+    ("DeciM", "dm"),
+    ("CentiM", "cm"),
+    ("MilliM", "mm"),
+    ("MicroM", "um"),
+    ("NanoM", "nm"),
+    ("ANGSTROM", "Ao"),
+    ("IN", "[in_i]"),
+    ("FT", "[ft_i]"),
+    ("YD", "[yd_i]"),
+    ("MI", "[mi_i]"),
+    ("MI_N", "[nmi_i]"),
+    ("FATH", "[fth_i]"),
+    // Area
+    ("M2", "m2"),
+    ("KiloM2", "km2"),
+    ("CentiM2", "cm2"),
+    ("MilliM2", "mm2"),
+    ("IN2", "[in_i]2"),
+    ("FT2", "[ft_i]2"),
+    ("YD2", "[yd_i]2"),
+    ("MI2", "[mi_i]2"),
+    ("ARE", "ar"),
+    ("HA", "har"),
+    ("AC", "[acr_us]"),
+    // Volume
+    ("M3", "m3"),
+    ("DeciM3", "dm3"),
+    ("CentiM3", "cm3"),
+    ("MilliM3", "mm3"),
+    ("L", "L"),
+    ("L", "l"),
+    ("DeciL", "dL"),
+    ("CentiL", "cL"),
+    ("MilliL", "mL"),
+    ("MicroL", "uL"),
+    ("IN3", "[in_i]3"),
+    ("FT3", "[ft_i]3"),
+    ("YD3", "[yd_i]3"),
+    ("GAL_US", "[gal_us]"),
+    ("QT_US", "[qt_us]"),
+    ("PINT_US", "[pt_us]"),
+    ("OZ_VOL_US", "[foz_us]"),
+    ("CUP_US", "[cup_us]"),
+    ("TBSP", "[tbs_us]"),
+    ("TSP", "[tsp_us]"),
+    ("BBL_US", "[bbl_us]"),
+    ("BU_US", "[bu_us]"),
+    ("GAL_UK", "[gal_br]"),
+    ("QT_UK", "[qt_br]"),
+    ("PINT_UK", "[pt_br]"),
+    ("OZ_VOL_UK", "[foz_br]"),
+    ("BU_UK", "[bu_br]"),
+    // Mass
+    ("KiloGM", "kg"),
+    ("GM", "g"),
+    ("MilliGM", "mg"),
+    ("MicroGM", "ug"),
+    ("TONNE", "t"),
+    ("LB", "[lb_av]"),
+    ("OZ", "[oz_av]"),
+    ("TON_SHORT", "[ston_av]"),
+    ("TON_LONG", "[lton_av]"),
+    ("OZ_TROY", "[oz_tr]"),
+    ("GRAIN", "[gr]"),
+    ("CARAT", "[car_m]"),
+    // Time
+    ("SEC", "s"),
+    ("MilliSEC", "ms"),
+    ("MicroSEC", "us"),
+    ("NanoSEC", "ns"),
+    ("MIN", "min"),
+    ("HR", "h"),
+    ("DAY", "d"),
+    ("WK", "wk"),
+    ("MO", "mo"),
+    ("YR", "a"),
+    // Temperature
+    ("K", "K"),
+    ("DEG_C", "Cel"),
+    ("DEG_F", "[degF]"),
+    ("DEG_R", "[degR]"),
+    // Plane angle
+    ("RAD", "rad"),
+    ("DEG", "deg"),
+    ("ARCMIN", "'"),
+    ("ARCSEC", "''"),
+    ("GON", "gon"),
+    // Velocity & acceleration
+    ("M-PER-SEC", "m/s"),
+    ("KiloM-PER-HR", "km/h"),
+    ("MI-PER-HR", "[mi_i]/h"),
+    ("FT-PER-SEC", "[ft_i]/s"),
+    ("KN", "[kn_i]"),
+    ("M-PER-SEC2", "m/s2"),
+    // Frequency & radioactivity
+    ("HZ", "Hz"),
+    ("KiloHZ", "kHz"),
+    ("MegaHZ", "MHz"),
+    ("GigaHZ", "GHz"),
+    ("BQ", "Bq"),
+    ("CI", "Ci"),
+    // Force, torque & pressure
+    ("N", "N"),
+    ("KiloN", "kN"),
+    ("DYN", "dyn"),
+    ("LB_F", "[lbf_av]"),
+    ("N-M", "N.m"),
+    ("PA", "Pa"),
+    ("KiloPA", "kPa"),
+    ("MegaPA", "MPa"),
+    ("BAR", "bar"),
+    ("MilliBAR", "mbar"),
+    ("ATM", "atm"),
+    ("PSI", "[psi]"),
+    // Energy & power
+    ("J", "J"),
+    ("KiloJ", "kJ"),
+    ("MegaJ", "MJ"),
+    ("W-HR", "W.h"),
+    ("KiloW-HR", "kW.h"),
+    ("MegaW-HR", "MW.h"),
+    ("CAL_IT", "cal_IT"),
+    ("EV", "eV"),
+    ("BTU_IT", "[Btu_IT]"),
+    ("W", "W"),
+    ("KiloW", "kW"),
+    ("MegaW", "MW"),
+    ("GigaW", "GW"),
+    // Electromagnetism
+    ("A", "A"),
+    ("MilliA", "mA"),
+    ("A-HR", "A.h"),
+    ("C", "C"),
+    ("V", "V"),
+    ("KiloV", "kV"),
+    ("MilliV", "mV"),
+    ("OHM", "Ohm"),
+    ("S", "S"),
+    ("FARAD", "F"),
+    ("WB", "Wb"),
+    ("T", "T"),
+    ("H", "H"),
+    // Radiation dose
+    ("GRAY", "Gy"),
+    ("SV", "Sv"),
+    // Light
+    ("CD", "cd"),
+    ("LM", "lm"),
+    ("LUX", "lx"),
+    // Amount of substance
+    ("MOL", "mol"),
+    ("KiloMOL", "kmol"),
+    ("MilliMOL", "mmol"),
+    ("MicroMOL", "umol"),
+    // Density & flow
+    ("KiloGM-PER-M3", "kg/m3"),
+    ("GM-PER-L", "g/L"),
+    ("M3-PER-HR", "m3/h"),
+    ("M3-PER-SEC", "m3/s"),
+    ("L-PER-MIN", "L/min"),
+    // Information
+    ("BIT", "bit"),
+    ("BYTE", "By"),
+    ("KiloBYTE", "kBy"),
+    ("MegaBYTE", "MBy"),
+    ("GigaBYTE", "GBy"),
+    ("TeraBYTE", "TBy"),
+];
+
+/// The QUDT local names in `MAPPINGS`, by the terms of the units they map to;
+/// `MAPPINGS` is only parsed the first time this is called.
+///
+fn local_names_by_terms() -> &'static HashMap<Vec<String>, &'static str> {
+    static INDEX: OnceLock<HashMap<Vec<String>, &'static str>> = OnceLock::new();
+
+    INDEX.get_or_init(|| index_by_terms(MAPPINGS))
+}
+
+impl Unit {
+    /// Finds the QUDT unit IRI for this `Unit`. Only units that are defined in
+    /// the exact same terms as a known mapping are considered a match (so `Hz`
+    /// never maps to the identifier for `Bq`).
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("kg/m3").unwrap();
+    /// assert_eq!(unit.to_qudt().unwrap(), "http://qudt.org/vocab/unit/KiloGM-PER-M3");
+    ///
+    /// let unit = Unit::from_str("[hp_C]").unwrap();
+    /// assert!(unit.to_qudt().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoExactMapping` if there's no exact QUDT equivalent.
+    ///
+    pub fn to_qudt(&self) -> Result<String, Error> {
+        local_names_by_terms()
+            .get(&self.sorted_term_strings())
+            .map(|local_name| format!("{QUDT_UNIT_NAMESPACE}{local_name}"))
+            .ok_or_else(|| Error::NoExactMapping {
+                code_system: CODE_SYSTEM.to_string(),
+                value: self.expression(),
+            })
+    }
+
+    /// Builds the `Unit` that's equivalent to the given QUDT unit. The unit can
+    /// be given as a full IRI (`http://qudt.org/vocab/unit/KiloGM`) or as a
+    /// CURIE (`unit:KiloGM`).
+    ///
+    /// ```rust
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_qudt("http://qudt.org/vocab/unit/KiloGM").unwrap();
+    /// assert_eq!(unit.expression(), "kg");
+    ///
+    /// let unit = Unit::from_qudt("unit:DEG_C").unwrap();
+    /// assert_eq!(unit.expression(), "Cel");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoExactMapping` if the identifier has no exact UCUM
+    /// equivalent (or isn't a QUDT unit identifier at all).
+    ///
+    pub fn from_qudt(identifier: &str) -> Result<Self, Error> {
+        let identifier = identifier.trim();
+
+        let local_name = identifier
+            .strip_prefix(QUDT_UNIT_NAMESPACE)
+            .or_else(|| identifier.strip_prefix(CURIE_PREFIX));
+
+        local_name
+            .and_then(|local_name| MAPPINGS.iter().find(|(name, _)| *name == local_name))
+            .map_or_else(
+                || {
+                    Err(Error::NoExactMapping {
+                        code_system: CODE_SYSTEM.to_string(),
+                        value: identifier.to_string(),
+                    })
+                },
+                |(_, expression)| Self::from_str(expression),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Unit, MAPPINGS, QUDT_UNIT_NAMESPACE};
+    use crate::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_mappings_parse() {
+        for (local_name, expression) in MAPPINGS {
+            assert!(
+                Unit::from_str(expression).is_ok(),
+                "{} maps to unparsable {}",
+                local_name,
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn validate_round_trips() {
+        for (local_name, _) in MAPPINGS {
+            let iri = format!("{}{}", QUDT_UNIT_NAMESPACE, local_name);
+            let unit = Unit::from_qudt(&iri).unwrap();
+            assert_eq!(unit.to_qudt().unwrap(), iri);
+        }
+    }
+
+    #[test]
+    fn validate_to_qudt() {
+        let unit = Unit::from_str("h").unwrap();
+        assert_eq!(unit.to_qudt().unwrap(), "http://qudt.org/vocab/unit/HR");
+
+        let unit = Unit::from_str("[in_i]/s").unwrap();
+        assert_eq!(
+            unit.to_qudt(),
+            Err(Error::NoExactMapping {
+                code_system: "QUDT".to_string(),
+                value: "[in_i]/s".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn validate_from_qudt() {
+        assert_eq!(Unit::from_qudt("unit:MilliM").unwrap().expression(), "mm");
+        assert!(Unit::from_qudt("MilliM").is_err());
+        assert!(Unit::from_qudt("http://qudt.org/vocab/unit/FOO").is_err());
+    }
+}
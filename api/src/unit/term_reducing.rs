@@ -1,11 +1,17 @@
-use crate::parser::{Atom, Prefix, Term};
-use std::collections::BTreeMap;
+use crate::{
+    parser::{Atom, Prefix, Term},
+    Error,
+};
+use std::{
+    cmp::Ordering,
+    collections::{btree_map::Entry, BTreeMap},
+};
 
 /// Internal struct used for reducing `Term`s.
 ///
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Eq, PartialOrd, Ord)]
 struct ComposableTerm {
-    factor: Option<u32>,
+    factor: Option<u64>,
     prefix: Option<Prefix>,
     atom: Option<Atom>,
     annotation: Option<String>,
@@ -17,6 +23,16 @@ impl ComposableTerm {
     }
 }
 
+/// `Atom`'s `PartialEq` considers atoms with the same dimension and scalar
+/// (ex. `Gy` and `Sv`) equal, but those must be kept as separate terms here.
+/// This keeps equality consistent with `Ord`, which `BTreeMap` relies on.
+///
+impl PartialEq for ComposableTerm {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
 impl<'a> From<&'a Term> for ComposableTerm {
     fn from(term: &'a Term) -> Self {
         Self {
@@ -46,15 +62,27 @@ impl From<Parts> for Term {
 
 /// Function used in `Unit` for reducing its `Term`s.
 ///
+/// # Panics
+///
+/// Panics if summing the exponents of like `Term`s overflows; see
+/// `try_reduce_terms()`.
+///
 pub(super) fn reduce_terms(terms: &[Term]) -> Vec<Term> {
-    let map = reduce_to_map(terms);
+    try_reduce_terms(terms).unwrap_or_else(|error| panic!("Unable to reduce terms: {}", error))
+}
+
+/// Same as `reduce_terms()`, but returns an `Error::Overflow` if summing the
+/// exponents of like `Term`s overflows.
+///
+pub(super) fn try_reduce_terms(terms: &[Term]) -> Result<Vec<Term>, Error> {
+    let map = reduce_to_map(terms)?;
 
     // If everything is reduced away, the effective Unit should be "1".
     if map.is_empty() {
-        vec![Term::new_unity()]
+        Ok(vec![Term::new_unity()])
     } else {
         // Reconstructs the map into the Vec<Term>.
-        map.into_iter().map(Term::from).collect()
+        Ok(map.into_iter().map(Term::from).collect())
     }
 }
 
@@ -62,23 +90,32 @@ pub(super) fn reduce_terms(terms: &[Term]) -> Vec<Term> {
 /// uniqueness (`atom`, `prefix`, `factor`), and sums those exponents. This is the destructuring
 /// part of `reduce_terms()`.
 ///
-fn reduce_to_map(terms: &[Term]) -> BTreeMap<ComposableTerm, i32> {
-    terms
-        .iter()
-        .map(|term| (ComposableTerm::from(term), term.exponent.unwrap_or(1)))
-        .fold(
-            BTreeMap::<ComposableTerm, i32>::new(),
-            |mut map, (key, exponent)| {
-                let _ = map
-                    .entry(key)
-                    .and_modify(|entry| *entry += exponent)
-                    .or_insert(exponent);
+fn reduce_to_map(terms: &[Term]) -> Result<BTreeMap<ComposableTerm, i32>, Error> {
+    let mut map = BTreeMap::<ComposableTerm, i32>::new();
+
+    for term in terms {
+        let exponent = term.exponent.unwrap_or(1);
+
+        match map.entry(ComposableTerm::from(term)) {
+            Entry::Occupied(mut entry) => {
+                let sum = entry
+                    .get()
+                    .checked_add(exponent)
+                    .ok_or_else(|| Error::Overflow {
+                        term: term.to_string(),
+                    })?;
+
+                let _ = entry.insert(sum);
+            }
+            Entry::Vacant(entry) => {
+                let _ = entry.insert(exponent);
+            }
+        }
+    }
 
-                map
-            },
-        )
+    Ok(map
         .into_iter()
         // Filter out things that have no values
         .filter(|(ct, exponent)| ct.has_value() && *exponent != 0)
-        .collect()
+        .collect())
 }
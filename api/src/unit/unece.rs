@@ -0,0 +1,321 @@
+//! Mappings between UCUM unit expressions and UN/CEFACT (UNECE) Recommendation
+//! 20 common codes.
+//!
+use super::{index_by_terms, Unit};
+use crate::Error;
+use std::{collections::HashMap, str::FromStr, sync::OnceLock};
+
+const CODE_SYSTEM: &str = "UNECE Rec 20";
+
+/// Pairs of UNECE Rec 20 common codes and the UCUM expression they're
+/// equivalent to. A code may be listed more than once (to account for
+/// alternate UCUM spellings); the first entry is used for `Unit::from_unece()`.
+///
+const MAPPINGS: &[(&str, &str)] = &[
+    // Dimensionless
+    ("C62", "1"),
+    ("P1", "%"),
+    ("59", "[ppm]"),
+    ("61", "[ppb]"),
+    // Length
+    ("MTR", "m"),
+    ("KMT", "km"),
+    ("HMT", "hm"),
+    ("A45", "dam"),
+    ("DMT", "dm"),
+    ("CMT", "cm"),
+    ("MMT", "mm"),
+    ("4H", "um"),
+    ("C45", "nm"),
+    ("A11", "Ao"),
+    ("INH", "[in_i]"),
+    ("FOT", "[ft_i]"),
+    ("YRD", "[yd_i]"),
+    ("SMI", "[mi_i]"),
+    ("NMI", "[nmi_i]"),
+    ("AK", "[fth_i]"),
+    // Area
+    ("MTK", "m2"),
+    ("KMK", "km2"),
+    ("CMK", "cm2"),
+    ("MMK", "mm2"),
+    ("INK", "[in_i]2"),
+    ("FTK", "[ft_i]2"),
+    ("YDK", "[yd_i]2"),
+    ("MIK", "[mi_i]2"),
+    ("ARE", "ar"),
+    ("HAR", "har"),
+    ("ACR", "[acr_us]"),
+    // Volume
+    ("MTQ", "m3"),
+    ("DMQ", "dm3"),
+    ("CMQ", "cm3"),
+    ("MMQ", "mm3"),
+    ("LTR", "L"),
+    ("LTR", "l"),
+    ("HLT", "hL"),
+    ("DLT", "dL"),
+    ("CLT", "cL"),
+    ("MLT", "mL"),
+    ("4G", "uL"),
+    ("INQ", "[in_i]3"),
+    ("FTQ", "[ft_i]3"),
+    ("YDQ", "[yd_i]3"),
+    ("GLL", "[gal_us]"),
+    ("QT", "[qt_us]"),
+    ("PT", "[pt_us]"),
+    ("OZA", "[foz_us]"),
+    ("G21", "[cup_us]"),
+    ("G24", "[tbs_us]"),
+    ("G25", "[tsp_us]"),
+    ("BLL", "[bbl_us]"),
+    ("BUA", "[bu_us]"),
+    ("GLI", "[gal_br]"),
+    ("QTI", "[qt_br]"),
+    ("PTI", "[pt_br]"),
+    ("OZI", "[foz_br]"),
+    ("BUI", "[bu_br]"),
+    // Mass
+    ("KGM", "kg"),
+    ("HGM", "hg"),
+    ("GRM", "g"),
+    ("MGM", "mg"),
+    ("MC", "ug"),
+    ("TNE", "t"),
+    ("DTN", "dt"),
+    ("LBR", "[lb_av]"),
+    ("ONZ", "[oz_av]"),
+    ("STN", "[ston_av]"),
+    ("LTN", "[lton_av]"),
+    ("APZ", "[oz_tr]"),
+    ("GRN", "[gr]"),
+    ("CTM", "[car_m]"),
+    // Time
+    ("SEC", "s"),
+    ("C26", "ms"),
+    ("B98", "us"),
+    ("C47", "ns"),
+    ("MIN", "min"),
+    ("HUR", "h"),
+    ("DAY", "d"),
+    ("WEE", "wk"),
+    ("MON", "mo"),
+    ("ANN", "a"),
+    // Temperature
+    ("KEL", "K"),
+    ("CEL", "Cel"),
+    ("FAH", "[degF]"),
+    ("A48", "[degR]"),
+    // Plane angle
+    ("C81", "rad"),
+    ("DD", "deg"),
+    ("D61", "'"),
+    ("D62", "''"),
+    ("A91", "gon"),
+    // Velocity & acceleration
+    ("MTS", "m/s"),
+    ("KMH", "km/h"),
+    ("HM", "[mi_i]/h"),
+    ("FS", "[ft_i]/s"),
+    ("KNT", "[kn_i]"),
+    ("MSK", "m/s2"),
+    // Frequency & radioactivity
+    ("HTZ", "Hz"),
+    ("KHZ", "kHz"),
+    ("MHZ", "MHz"),
+    ("A86", "GHz"),
+    ("BQL", "Bq"),
+    ("CUR", "Ci"),
+    // Force, torque & pressure
+    ("NEW", "N"),
+    ("B47", "kN"),
+    ("DU", "dyn"),
+    ("C78", "[lbf_av]"),
+    ("NU", "N.m"),
+    ("PAL", "Pa"),
+    ("KPA", "kPa"),
+    ("MPA", "MPa"),
+    ("BAR", "bar"),
+    ("MBR", "mbar"),
+    ("ATM", "atm"),
+    ("PS", "[psi]"),
+    // Energy & power
+    ("JOU", "J"),
+    ("KJO", "kJ"),
+    ("3B", "MJ"),
+    ("WHR", "W.h"),
+    ("KWH", "kW.h"),
+    ("MWH", "MW.h"),
+    ("GWH", "GW.h"),
+    ("D70", "cal_IT"),
+    ("A53", "eV"),
+    ("BTU", "[Btu_IT]"),
+    ("WTT", "W"),
+    ("KWT", "kW"),
+    ("MAW", "MW"),
+    ("A90", "GW"),
+    // Electromagnetism
+    ("AMP", "A"),
+    ("4K", "mA"),
+    ("AMH", "A.h"),
+    ("COU", "C"),
+    ("VLT", "V"),
+    ("KVT", "kV"),
+    ("2Z", "mV"),
+    ("OHM", "Ohm"),
+    ("SIE", "S"),
+    ("FAR", "F"),
+    ("WEB", "Wb"),
+    ("D33", "T"),
+    ("81", "H"),
+    // Radiation dose
+    ("A95", "Gy"),
+    ("D13", "Sv"),
+    // Light
+    ("CDL", "cd"),
+    ("LUM", "lm"),
+    ("LUX", "lx"),
+    // Amount of substance
+    ("C34", "mol"),
+    ("B45", "kmol"),
+    ("C18", "mmol"),
+    ("FH", "umol"),
+    // Density & flow
+    ("KMQ", "kg/m3"),
+    ("GL", "g/L"),
+    ("MQH", "m3/h"),
+    ("MQS", "m3/s"),
+    ("L2", "L/min"),
+    // Information
+    ("A99", "bit"),
+    ("AD", "By"),
+    ("2P", "kBy"),
+    ("4L", "MBy"),
+    ("E34", "GBy"),
+    ("E35", "TBy"),
+];
+
+/// The UNECE codes in `MAPPINGS`, by the terms of the units they map to;
+/// `MAPPINGS` is only parsed the first time this is called.
+///
+fn codes_by_terms() -> &'static HashMap<Vec<String>, &'static str> {
+    static INDEX: OnceLock<HashMap<Vec<String>, &'static str>> = OnceLock::new();
+
+    INDEX.get_or_init(|| index_by_terms(MAPPINGS))
+}
+
+impl Unit {
+    /// Finds the UNECE Recommendation 20 common code for this `Unit`. Only
+    /// units that are defined in the exact same terms as a known mapping are
+    /// considered a match (so `Hz` never maps to the code for `Bq`);
+    /// equivalent-but-differently-expressed units (ex. `1000.g` for `kg`) are
+    /// not.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("km/h").unwrap();
+    /// assert_eq!(unit.to_unece().unwrap(), "KMH");
+    ///
+    /// let unit = Unit::from_str("[hp_C]").unwrap();
+    /// assert!(unit.to_unece().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoExactMapping` if there's no exact UNECE equivalent.
+    ///
+    pub fn to_unece(&self) -> Result<&'static str, Error> {
+        codes_by_terms()
+            .get(&self.sorted_term_strings())
+            .copied()
+            .ok_or_else(|| Error::NoExactMapping {
+                code_system: CODE_SYSTEM.to_string(),
+                value: self.expression(),
+            })
+    }
+
+    /// Builds the `Unit` that's equivalent to the given UNECE Recommendation 20
+    /// common code.
+    ///
+    /// ```rust
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_unece("KGM").unwrap();
+    /// assert_eq!(unit.expression(), "kg");
+    ///
+    /// assert!(Unit::from_unece("ZZZ").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoExactMapping` if the code has no exact UCUM equivalent
+    /// (or isn't a known code).
+    ///
+    pub fn from_unece(code: &str) -> Result<Self, Error> {
+        let code = code.trim();
+
+        MAPPINGS.iter().find(|(c, _)| *c == code).map_or_else(
+            || {
+                Err(Error::NoExactMapping {
+                    code_system: CODE_SYSTEM.to_string(),
+                    value: code.to_string(),
+                })
+            },
+            |(_, expression)| Self::from_str(expression),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Unit, MAPPINGS};
+    use crate::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_mappings_parse() {
+        for (code, expression) in MAPPINGS {
+            assert!(
+                Unit::from_str(expression).is_ok(),
+                "{} maps to unparsable {}",
+                code,
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn validate_round_trips() {
+        for (code, _) in MAPPINGS {
+            let unit = Unit::from_unece(code).unwrap();
+            assert_eq!(unit.to_unece().unwrap(), *code);
+        }
+    }
+
+    #[test]
+    fn validate_to_unece() {
+        let unit = Unit::from_str("l").unwrap();
+        assert_eq!(unit.to_unece().unwrap(), "LTR");
+
+        let unit = Unit::from_str("m.s-1").unwrap();
+        assert_eq!(unit.to_unece().unwrap(), "MTS");
+
+        let unit = Unit::from_str("1000.g").unwrap();
+        assert_eq!(
+            unit.to_unece(),
+            Err(Error::NoExactMapping {
+                code_system: "UNECE Rec 20".to_string(),
+                value: "1000.g".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn validate_from_unece() {
+        assert_eq!(Unit::from_unece("HUR").unwrap().expression(), "h");
+        assert_eq!(Unit::from_unece(" CEL ").unwrap().expression(), "Cel");
+        assert!(Unit::from_unece("EA").is_err());
+    }
+}
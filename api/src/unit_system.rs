@@ -0,0 +1,373 @@
+//! Unit-system presets (metric, US customary, imperial) for picking the unit
+//! to display a `Measurement` in.
+//!
+//! A `UnitSystem` maps `Composition`s (ex. length, volume, mass) to the units
+//! that system uses for them, and picks between those by magnitude: with US
+//! customary units, `30 cm` becomes inches, `3 m` becomes feet, and `3 km`
+//! becomes miles.
+//!
+//! ```rust
+//! use wise_units::{Measurement, UnitSystem};
+//!
+//! let distance = Measurement::try_new(3.0, "km").unwrap();
+//! let converted = UnitSystem::us_customary().convert(&distance).unwrap();
+//!
+//! assert_eq!(converted.unit().expression(), "[mi_i]");
+//! ```
+//!
+use crate::{
+    composition,
+    parser::{Atom, Classification, Composable, Composition, Prefix, Property, Term, UcumSymbol},
+    ucum_unit::UcumUnit,
+    Convertible, Error, Measurement, Unit,
+};
+
+/// A set of target units, by `Composition`, along with the `Classification`s
+/// of the units that belong to the system.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitSystem {
+    classifications: Vec<Classification>,
+    units: Vec<(Composition, Vec<Unit>)>,
+}
+
+impl UnitSystem {
+    /// SI and other metric units: `mm`/`cm`/`m`/`km`, `mL`/`L`, `mg`/`g`/`kg`,
+    /// `Cel` and `km/h`.
+    ///
+    #[must_use]
+    pub fn metric() -> Self {
+        Self {
+            classifications: vec![Classification::Si, Classification::Iso1000],
+            units: vec![
+                (
+                    composition::LENGTH,
+                    vec![
+                        unit(Some(Prefix::Milli), Atom::Meter),
+                        unit(Some(Prefix::Centi), Atom::Meter),
+                        unit(None, Atom::Meter),
+                        unit(Some(Prefix::Kilo), Atom::Meter),
+                    ],
+                ),
+                (
+                    composition::VOLUME,
+                    vec![
+                        unit(Some(Prefix::Milli), Atom::LiterSecondary),
+                        unit(None, Atom::LiterSecondary),
+                    ],
+                ),
+                (
+                    composition::MASS,
+                    vec![
+                        unit(Some(Prefix::Milli), Atom::Gram),
+                        unit(None, Atom::Gram),
+                        unit(Some(Prefix::Kilo), Atom::Gram),
+                    ],
+                ),
+                (
+                    composition::TEMPERATURE,
+                    vec![unit(None, Atom::DegreeCelsius)],
+                ),
+                (
+                    composition::VELOCITY,
+                    vec![per_hour(Some(Prefix::Kilo), Atom::Meter)],
+                ),
+            ],
+        }
+    }
+
+    /// US customary units: `[in_i]`/`[ft_i]`/`[mi_i]`, `[foz_us]`/`[gal_us]`,
+    /// `[oz_av]`/`[lb_av]`, `[degF]` and `[mi_i]/h`.
+    ///
+    #[must_use]
+    pub fn us_customary() -> Self {
+        Self {
+            classifications: vec![
+                Classification::Intcust,
+                Classification::UsLengths,
+                Classification::UsVolumes,
+                Classification::Avoirdupois,
+                Classification::Heat,
+            ],
+            units: customary_units(Atom::FluidOunceUS, Atom::QueenAnnesWineGallonUS),
+        }
+    }
+
+    /// British imperial units: the same as `us_customary()`, but with British
+    /// volumes (`[foz_br]`/`[gal_br]`).
+    ///
+    #[must_use]
+    pub fn imperial() -> Self {
+        Self {
+            classifications: vec![
+                Classification::Intcust,
+                Classification::BritLength,
+                Classification::BritVolumes,
+                Classification::Avoirdupois,
+                Classification::Heat,
+            ],
+            units: customary_units(Atom::FluidOunceBritish, Atom::GallonBritish),
+        }
+    }
+
+    /// Sets the units to pick from for measurements of `composition`,
+    /// replacing any the system already had. The units are sorted by size, so
+    /// they can be given in any order.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{composition, Measurement, Unit, UnitSystem};
+    ///
+    /// let system = UnitSystem::metric()
+    ///     .with_units(composition::PRESSURE, vec![Unit::from_str("kPa").unwrap()]);
+    /// let pressure = Measurement::try_new(120.0, "mm[Hg]").unwrap();
+    ///
+    /// assert_eq!(system.convert(&pressure).unwrap().unit().expression(), "kPa");
+    /// ```
+    ///
+    #[must_use]
+    pub fn with_units(mut self, composition: Composition, mut units: Vec<Unit>) -> Self {
+        units.sort_by(|lhs, rhs| lhs.scalar().total_cmp(&rhs.scalar()));
+
+        match self.units.iter_mut().find(|(c, _)| *c == composition) {
+            Some((_, existing)) => *existing = units,
+            None => self.units.push((composition, units)),
+        }
+
+        self
+    }
+
+    /// The units the system uses for measurements of `composition`, smallest
+    /// first.
+    ///
+    #[must_use]
+    pub fn units_for(&self, composition: Composition) -> Option<&[Unit]> {
+        self.units
+            .iter()
+            .find(|(c, _)| *c == composition)
+            .map(|(_, units)| units.as_slice())
+    }
+
+    /// Checks if every atom in `unit` belongs to the system, using each
+    /// atom's `Classification`. This keeps US and British units apart (ex.
+    /// `[gal_us]` isn't imperial, and `[gal_br]` isn't US customary). Units of
+    /// time (ex. `s`, `h`) are shared by all systems.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{Unit, UnitSystem};
+    ///
+    /// let gallons = Unit::from_str("[gal_us]").unwrap();
+    ///
+    /// assert!(UnitSystem::us_customary().contains(&gallons));
+    /// assert!(!UnitSystem::imperial().contains(&gallons));
+    /// ```
+    ///
+    #[must_use]
+    pub fn contains(&self, unit: &Unit) -> bool {
+        unit.terms()
+            .iter()
+            .filter_map(|term| term.atom)
+            .all(|atom| {
+                atom.property() == Property::Time
+                    || self.classifications.contains(&atom.classification())
+            })
+    }
+
+    /// Picks the unit that `measurement` should be converted to: of the
+    /// system's units for `measurement`'s `Composition`, the largest one in
+    /// which the value is at least 1 (or the smallest one, if there's none).
+    /// Returns `None` if the system has no units for the `Composition`.
+    ///
+    #[must_use]
+    pub fn target_unit(&self, measurement: &Measurement) -> Option<&Unit> {
+        let units = self.units_for(measurement.composition())?;
+
+        units
+            .iter()
+            .rev()
+            .find(|unit| {
+                measurement
+                    .convert_to(*unit)
+                    .is_ok_and(|converted| converted.value().abs() >= 1.0)
+            })
+            .or_else(|| units.first())
+    }
+
+    /// Converts `measurement` to the unit picked by `target_unit()`. If the
+    /// system has no units for `measurement`'s `Composition`, `measurement`
+    /// is returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` can't be
+    /// converted to the target unit (ex. because it's annotated, like
+    /// `mg{creat}`).
+    ///
+    pub fn convert(&self, measurement: &Measurement) -> Result<Measurement, Error> {
+        self.target_unit(measurement).map_or_else(
+            || Ok(measurement.clone()),
+            |unit| measurement.convert_to(unit),
+        )
+    }
+}
+
+fn unit(prefix: Option<Prefix>, atom: Atom) -> Unit {
+    Unit::new(vec![Term::new(prefix, Some(atom))])
+}
+
+fn per_hour(prefix: Option<Prefix>, atom: Atom) -> Unit {
+    let mut hour = Term::new(None, Some(Atom::Hour));
+    hour.exponent = Some(-1);
+
+    Unit::new(vec![Term::new(prefix, Some(atom)), hour])
+}
+
+/// The units shared by US customary and imperial systems, with the given
+/// volume units.
+///
+fn customary_units(fluid_ounce: Atom, gallon: Atom) -> Vec<(Composition, Vec<Unit>)> {
+    vec![
+        (
+            composition::LENGTH,
+            vec![
+                unit(None, Atom::InchInternational),
+                unit(None, Atom::FootInternational),
+                unit(None, Atom::MileInternational),
+            ],
+        ),
+        (
+            composition::VOLUME,
+            vec![unit(None, fluid_ounce), unit(None, gallon)],
+        ),
+        (
+            composition::MASS,
+            vec![
+                unit(None, Atom::OunceAvoirdupois),
+                unit(None, Atom::PoundAvoirdupois),
+            ],
+        ),
+        (
+            composition::TEMPERATURE,
+            vec![unit(None, Atom::DegreeFahrenheit)],
+        ),
+        (
+            composition::VELOCITY,
+            vec![per_hour(None, Atom::MileInternational)],
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    fn convert(system: &UnitSystem, value: f64, expression: &str) -> Measurement {
+        system.convert(&measurement(value, expression)).unwrap()
+    }
+
+    #[test]
+    fn validate_metric() {
+        let system = UnitSystem::metric();
+
+        let converted = convert(&system, 12.0, "[in_i]");
+        assert_eq!(converted.unit().expression(), "cm");
+        assert_relative_eq!(converted.value(), 30.48);
+
+        assert_eq!(convert(&system, 5.0, "[mi_i]").unit().expression(), "km");
+        assert_eq!(convert(&system, 0.5, "[foz_us]").unit().expression(), "mL");
+        assert_eq!(convert(&system, 1.0, "[gal_br]").unit().expression(), "L");
+        assert_eq!(convert(&system, 3.0, "[lb_av]").unit().expression(), "kg");
+        assert_eq!(
+            convert(&system, 60.0, "[mi_i]/h").unit().expression(),
+            "km/h"
+        );
+
+        let converted = convert(&system, 212.0, "[degF]");
+        assert_eq!(converted.unit().expression(), "Cel");
+        assert_relative_eq!(converted.value(), 100.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_us_customary() {
+        let system = UnitSystem::us_customary();
+
+        assert_eq!(convert(&system, 30.0, "cm").unit().expression(), "[in_i]");
+        assert_eq!(convert(&system, 3.0, "m").unit().expression(), "[ft_i]");
+        assert_eq!(convert(&system, 3.0, "km").unit().expression(), "[mi_i]");
+        assert_eq!(convert(&system, 10.0, "mm").unit().expression(), "[in_i]");
+        assert_eq!(convert(&system, 5.0, "L").unit().expression(), "[gal_us]");
+        assert_eq!(
+            convert(&system, 1.0, "[gal_br]").unit().expression(),
+            "[gal_us]"
+        );
+        assert_eq!(convert(&system, 100.0, "g").unit().expression(), "[oz_av]");
+
+        let converted = convert(&system, 100.0, "km/h");
+        assert_eq!(converted.unit().expression(), "[mi_i]/h");
+        assert_relative_eq!(converted.value(), 62.137_119, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn validate_imperial() {
+        let system = UnitSystem::imperial();
+
+        let converted = convert(&system, 2.0, "[gal_us]");
+        assert_eq!(converted.unit().expression(), "[gal_br]");
+        assert_relative_eq!(converted.value(), 1.665_348, max_relative = 1e-6);
+
+        assert_eq!(
+            convert(&system, 100.0, "mL").unit().expression(),
+            "[foz_br]"
+        );
+    }
+
+    #[test]
+    fn validate_unchanged_without_units() {
+        let pressure = measurement(1.0, "kPa");
+        assert_eq!(
+            UnitSystem::us_customary().convert(&pressure).unwrap(),
+            pressure
+        );
+        assert!(UnitSystem::metric().target_unit(&pressure).is_none());
+    }
+
+    #[test]
+    fn validate_with_units() {
+        let system = UnitSystem::us_customary().with_units(
+            composition::LENGTH,
+            vec![
+                unit(None, Atom::YardInternational),
+                unit(None, Atom::InchInternational),
+            ],
+        );
+
+        assert_eq!(convert(&system, 3.0, "m").unit().expression(), "[yd_i]");
+        assert_eq!(convert(&system, 3.0, "cm").unit().expression(), "[in_i]");
+    }
+
+    #[test]
+    fn validate_contains() {
+        for system in [
+            UnitSystem::metric(),
+            UnitSystem::us_customary(),
+            UnitSystem::imperial(),
+        ] {
+            for (_, units) in &system.units {
+                for unit in units {
+                    assert!(system.contains(unit), "{}", unit);
+                }
+            }
+        }
+
+        let british_gallon = unit(None, Atom::GallonBritish);
+        assert!(UnitSystem::imperial().contains(&british_gallon));
+        assert!(!UnitSystem::us_customary().contains(&british_gallon));
+        assert!(!UnitSystem::metric().contains(&british_gallon));
+    }
+}
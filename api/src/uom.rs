@@ -0,0 +1,183 @@
+//! Conversions between `Measurement`s and `uom` quantities.
+//!
+//! `Measurement`s convert to `uom` quantities with `TryFrom`, which checks that
+//! the `Measurement`'s `Composition` matches the quantity's dimension.
+//! Quantities convert back with `From`, resulting in a `Measurement` in SI base
+//! units.
+//!
+//! ```rust
+//! use uom::si::{f64::Length, length::meter};
+//! use wise_units::Measurement;
+//!
+//! let measurement = Measurement::try_new(2.5, "km").unwrap();
+//! let length = Length::try_from(&measurement).unwrap();
+//! assert_eq!(length.get::<meter>(), 2500.0);
+//!
+//! let measurement = Measurement::from(length);
+//! assert_eq!(measurement.unit().expression(), "m");
+//!
+//! let mass = Measurement::try_new(1.0, "g").unwrap();
+//! assert!(Length::try_from(&mass).is_err());
+//! ```
+//!
+use crate::{
+    composition, parser::Composable, unit::si_unit_from_composition, Composition, Error,
+    Measurement,
+};
+use ::uom::si::{
+    acceleration::meter_per_second_squared,
+    area::square_meter,
+    electric_charge::coulomb,
+    energy::joule,
+    f64::{
+        Acceleration, Area, ElectricCharge, Energy, Force, Length, LuminousIntensity, Mass, Power,
+        Pressure, ThermodynamicTemperature, Time, Velocity, Volume,
+    },
+    force::newton,
+    length::meter,
+    luminous_intensity::candela,
+    mass::kilogram,
+    power::watt,
+    pressure::pascal,
+    thermodynamic_temperature::kelvin,
+    time::second,
+    velocity::meter_per_second,
+    volume::cubic_meter,
+};
+
+/// The value of `measurement` in SI base units, if it has the `expected`
+/// `Composition`.
+///
+fn si_value(measurement: &Measurement, expected: Composition) -> Result<f64, Error> {
+    if measurement.composition() != expected {
+        return Err(Error::IncompatibleUnitTypes {
+            lhs: measurement.unit().expression(),
+            rhs: si_unit_from_composition(expected, false).expression(),
+        });
+    }
+
+    measurement.to_si().map(|si| si.value())
+}
+
+macro_rules! impl_uom_quantity {
+    ($quantity:ident, $si_unit:ident, $composition:expr) => {
+        impl TryFrom<&Measurement> for $quantity {
+            type Error = Error;
+
+            fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+                si_value(measurement, $composition).map(Self::new::<$si_unit>)
+            }
+        }
+
+        impl TryFrom<Measurement> for $quantity {
+            type Error = Error;
+
+            fn try_from(measurement: Measurement) -> Result<Self, Self::Error> {
+                Self::try_from(&measurement)
+            }
+        }
+
+        impl From<$quantity> for Measurement {
+            fn from(quantity: $quantity) -> Self {
+                Self::new(
+                    quantity.get::<$si_unit>(),
+                    si_unit_from_composition($composition, false),
+                )
+            }
+        }
+    };
+}
+
+impl_uom_quantity!(Length, meter, composition::LENGTH);
+impl_uom_quantity!(Area, square_meter, composition::AREA);
+impl_uom_quantity!(Volume, cubic_meter, composition::VOLUME);
+impl_uom_quantity!(Mass, kilogram, composition::MASS);
+impl_uom_quantity!(Time, second, composition::TIME);
+impl_uom_quantity!(ThermodynamicTemperature, kelvin, composition::TEMPERATURE);
+impl_uom_quantity!(ElectricCharge, coulomb, composition::ELECTRIC_CHARGE);
+impl_uom_quantity!(LuminousIntensity, candela, composition::LUMINOUS_INTENSITY);
+impl_uom_quantity!(Velocity, meter_per_second, composition::VELOCITY);
+impl_uom_quantity!(
+    Acceleration,
+    meter_per_second_squared,
+    composition::ACCELERATION
+);
+impl_uom_quantity!(Force, newton, composition::FORCE);
+impl_uom_quantity!(Pressure, pascal, composition::PRESSURE);
+impl_uom_quantity!(Energy, joule, composition::ENEGERY);
+impl_uom_quantity!(Power, watt, composition::POWER);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::uom::si::{
+        length::inch, pressure::millimeter_of_mercury, thermodynamic_temperature::degree_celsius,
+        velocity::kilometer_per_hour,
+    };
+    use approx::assert_relative_eq;
+
+    fn measurement(value: f64, expression: &str) -> Measurement {
+        Measurement::try_new(value, expression).unwrap()
+    }
+
+    #[test]
+    fn validate_try_from_measurement() {
+        let length = Length::try_from(measurement(12.0, "[in_i]")).unwrap();
+        assert_relative_eq!(length.get::<inch>(), 12.0, max_relative = 1e-12);
+
+        let mass = Mass::try_from(&measurement(500.0, "g")).unwrap();
+        assert_relative_eq!(mass.get::<kilogram>(), 0.5);
+
+        let time = Time::try_from(&measurement(2.0, "h")).unwrap();
+        assert_relative_eq!(time.get::<second>(), 7200.0);
+
+        let velocity = Velocity::try_from(&measurement(90.0, "km/h")).unwrap();
+        assert_relative_eq!(
+            velocity.get::<kilometer_per_hour>(),
+            90.0,
+            max_relative = 1e-12
+        );
+
+        let pressure = Pressure::try_from(&measurement(120.0, "mm[Hg]")).unwrap();
+        assert_relative_eq!(
+            pressure.get::<millimeter_of_mercury>(),
+            120.0,
+            max_relative = 1e-5
+        );
+
+        let temperature = ThermodynamicTemperature::try_from(&measurement(37.0, "Cel")).unwrap();
+        assert_relative_eq!(
+            temperature.get::<degree_celsius>(),
+            37.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn validate_try_from_measurement_errors() {
+        assert_eq!(
+            Length::try_from(&measurement(1.0, "g")),
+            Err(Error::IncompatibleUnitTypes {
+                lhs: "g".to_string(),
+                rhs: "m".to_string()
+            })
+        );
+        assert!(Mass::try_from(&measurement(1.0, "mg{creat}")).is_err());
+        assert!(Pressure::try_from(&measurement(1.0, "J")).is_err());
+    }
+
+    #[test]
+    fn validate_from_quantity() {
+        let measurement = Measurement::from(Pressure::new::<pascal>(101_325.0));
+        assert_eq!(measurement.unit().expression(), "kg/m.s2");
+        assert_eq!(measurement, Measurement::try_new(1.0, "atm").unwrap());
+
+        let measurement = Measurement::from(ThermodynamicTemperature::new::<degree_celsius>(25.0));
+        assert_eq!(measurement.unit().expression(), "K");
+        assert_relative_eq!(measurement.value(), 298.15, max_relative = 1e-12);
+
+        let measurement = Measurement::from(Energy::new::<joule>(4.184));
+        assert_eq!(measurement.unit().expression(), "m2.kg/s2");
+        assert_eq!(measurement, Measurement::try_new(1.0, "cal_th").unwrap());
+    }
+}
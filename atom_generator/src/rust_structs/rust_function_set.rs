@@ -6,3 +6,239 @@ pub(crate) struct RustFunctionSet {
     // AKA "reduce_value"
     pub(crate) convert_to: String,
 }
+
+impl RustFunctionSet {
+    /// Looks up the functions for a special unit by the name of the UCUM
+    /// function that defines it; that's the `name` of the unit's
+    /// `[unit.value.function]` in `Atoms.toml` (or `CustomAtoms.toml`), which
+    /// comes from UCUM's `<function name="...">`. Names are also accepted the
+    /// way they're written in UCUM's `Unit` strings (ex. `cel(1 K)`,
+    /// `2lg(2 1)`).
+    ///
+    pub(crate) fn from_function_name(name: &str) -> Option<Self> {
+        let (convert_from, convert_to) = match name {
+            "Cel" | "cel" => ("|value: f64| value - 273.15", "|value: f64| value + 273.15"),
+            "degF" | "degf" => (
+                "|value: f64| 9.0 * value / 5.0 - 459.67",
+                "|value: f64| 5.0 / 9.0 * (value + 459.67)",
+            ),
+            "degRe" | "degre" => (
+                "|value: f64| (value - 273.15) * 0.8",
+                "|value: f64| (value / 0.8) + 273.15",
+            ),
+            "pH" => (
+                "|value: f64| 10.0_f64.powf(-value)",
+                "|value: f64| -value.log10()",
+            ),
+            "ln" => ("|value: f64| value.ln()", "|value: f64| value.exp()"),
+            "lg" => (
+                "|value: f64| value.log10()",
+                "|value: f64| 10_f64.powf(value)",
+            ),
+            "lgTimes2" | "2lg" => (
+                "|value: f64| 2.0 * value.log10()",
+                "|value: f64| 10_f64.powf(value / 2.0)",
+            ),
+            "ld" => ("|value: f64| value.log2()", "|value: f64| value.exp2()"),
+            "tanTimes100" | "100tan" => (
+                "|value: f64| (value / 100.0).atan()",
+                "|value: f64| value.tan() * 100.0",
+            ),
+            "hpX" => (
+                "|value: f64| -value.log10()",
+                "|value: f64| 10_f64.powf(-value)",
+            ),
+            "hpC" => (
+                "|value: f64| -value.ln() / 100_f64.ln()",
+                "|value: f64| 100_f64.powf(-value)",
+            ),
+            "hpM" => (
+                "|value: f64| -value.ln() / 1_000_f64.ln()",
+                "|value: f64| 1_000_f64.powf(-value)",
+            ),
+            "hpQ" => (
+                "|value: f64| -value.ln() / 50_000_f64.ln()",
+                "|value: f64| 50_000_f64.powf(-value)",
+            ),
+            "sqrt" => ("|value: f64| value.sqrt()", "|value: f64| value * value"),
+            _ => return None,
+        };
+
+        Some(Self {
+            convert_from: convert_from.to_string(),
+            convert_to: convert_to.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::toml_structs::TomlAtomList;
+    use std::str::FromStr;
+
+    static ATOMS_FILE: &str = include_str!("../../Atoms.toml");
+
+    /// The functions that used to be picked by each special unit's primary
+    /// code, before they were picked by function name.
+    ///
+    const FUNCTIONS_BY_PRIMARY_CODE: [(&str, &str, &str); 21] = [
+        (
+            "B",
+            "|value: f64| value.log10()",
+            "|value: f64| 10_f64.powf(value)",
+        ),
+        (
+            "B[W]",
+            "|value: f64| value.log10()",
+            "|value: f64| 10_f64.powf(value)",
+        ),
+        (
+            "B[kW]",
+            "|value: f64| value.log10()",
+            "|value: f64| 10_f64.powf(value)",
+        ),
+        (
+            "B[SPL]",
+            "|value: f64| 2.0 * value.log10()",
+            "|value: f64| 10_f64.powf(value / 2.0)",
+        ),
+        (
+            "B[V]",
+            "|value: f64| 2.0 * value.log10()",
+            "|value: f64| 10_f64.powf(value / 2.0)",
+        ),
+        (
+            "B[mV]",
+            "|value: f64| 2.0 * value.log10()",
+            "|value: f64| 10_f64.powf(value / 2.0)",
+        ),
+        (
+            "B[uV]",
+            "|value: f64| 2.0 * value.log10()",
+            "|value: f64| 10_f64.powf(value / 2.0)",
+        ),
+        (
+            "B[10.nV]",
+            "|value: f64| 2.0 * value.log10()",
+            "|value: f64| 10_f64.powf(value / 2.0)",
+        ),
+        (
+            "bit_s",
+            "|value: f64| value.log2()",
+            "|value: f64| value.exp2()",
+        ),
+        (
+            "Cel",
+            "|value: f64| value - 273.15",
+            "|value: f64| value + 273.15",
+        ),
+        ("Np", "|value: f64| value.ln()", "|value: f64| value.exp()"),
+        (
+            "%[slope]",
+            "|value: f64| (value / 100.0).atan()",
+            "|value: f64| value.tan() * 100.0",
+        ),
+        (
+            "[p'diop]",
+            "|value: f64| (value / 100.0).atan()",
+            "|value: f64| value.tan() * 100.0",
+        ),
+        (
+            "[hp'_X]",
+            "|value: f64| -value.log10()",
+            "|value: f64| 10_f64.powf(-value)",
+        ),
+        (
+            "[hp'_C]",
+            "|value: f64| -value.ln() / 100_f64.ln()",
+            "|value: f64| 100_f64.powf(-value)",
+        ),
+        (
+            "[hp'_M]",
+            "|value: f64| -value.ln() / 1_000_f64.ln()",
+            "|value: f64| 1_000_f64.powf(-value)",
+        ),
+        (
+            "[hp'_Q]",
+            "|value: f64| -value.ln() / 50_000_f64.ln()",
+            "|value: f64| 50_000_f64.powf(-value)",
+        ),
+        (
+            "[m/s2/Hz^(1/2)]",
+            "|value: f64| value.sqrt()",
+            "|value: f64| value * value",
+        ),
+        (
+            "[pH]",
+            "|value: f64| 10.0_f64.powf(-value)",
+            "|value: f64| -value.log10()",
+        ),
+        (
+            "[degF]",
+            "|value: f64| 9.0 * value / 5.0 - 459.67",
+            "|value: f64| 5.0 / 9.0 * (value + 459.67)",
+        ),
+        (
+            "[degRe]",
+            "|value: f64| (value - 273.15) * 0.8",
+            "|value: f64| (value / 0.8) + 273.15",
+        ),
+    ];
+
+    fn atom_list() -> TomlAtomList {
+        TomlAtomList::from_str(ATOMS_FILE).expect("unable to deserialize Toml to TomlAtomList")
+    }
+
+    #[test]
+    fn validate_every_function_in_atoms_toml_resolves() {
+        for unit in atom_list().units {
+            if let Some(function) = unit.definition.function {
+                assert!(
+                    RustFunctionSet::from_function_name(&function.name).is_some(),
+                    "{}: {}",
+                    unit.primary_code,
+                    function.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate_functions_match_those_by_primary_code() {
+        let special_units: Vec<_> = atom_list()
+            .units
+            .into_iter()
+            .filter(|unit| unit.is_special)
+            .collect();
+
+        for (primary_code, _, _) in FUNCTIONS_BY_PRIMARY_CODE {
+            assert!(
+                special_units
+                    .iter()
+                    .any(|unit| unit.primary_code == primary_code),
+                "{primary_code}"
+            );
+        }
+
+        for unit in special_units {
+            let (_, convert_from, convert_to) = FUNCTIONS_BY_PRIMARY_CODE
+                .iter()
+                .find(|(primary_code, _, _)| *primary_code == unit.primary_code)
+                .unwrap_or_else(|| panic!("{}", unit.primary_code));
+            let function = unit.definition.function.unwrap();
+            let function_set = RustFunctionSet::from_function_name(&function.name).unwrap();
+
+            assert_eq!(
+                &function_set.convert_from, convert_from,
+                "{}",
+                unit.primary_code
+            );
+            assert_eq!(
+                &function_set.convert_to, convert_to,
+                "{}",
+                unit.primary_code
+            );
+        }
+    }
+}